@@ -60,6 +60,55 @@ impl_ring!(f64);
 impl_field!(f32);
 impl_field!(f64);
 
+/// A trait representing a Euclidean domain.
+///
+/// A Euclidean domain is an integral domain with a notion of division with remainder: for any
+/// `a` and non-zero `b` there are `q` and `r` with `a = q·b + r`, where either `r = 0` or `r` is
+/// strictly smaller than `b` under the Euclidean function. Every Euclidean domain is a principal
+/// ideal domain, which is exactly what is needed to diagonalize matrices via the Smith normal
+/// form (see [`crate::tensors::smith`]).
+///
+/// The primary example is the ring of integers ℤ, implemented here for the primitive signed
+/// integer types.
+pub trait EuclideanDomain: Ring + Copy + PartialEq {
+  /// Returns the value of the Euclidean function, which decreases along remainders.
+  fn euclidean_norm(&self) -> u128;
+
+  /// Divides `self` by a non-zero `divisor`, returning the quotient and remainder.
+  ///
+  /// # Panics
+  ///
+  /// This function may panic if `divisor` is zero.
+  fn div_rem(&self, divisor: &Self) -> (Self, Self);
+
+  /// Returns the canonical associate of `self` together with the unit `u` such that the
+  /// associate equals `u · self`.
+  ///
+  /// For the integers the canonical associate is the absolute value.
+  fn normalize(&self) -> (Self, Self);
+}
+
+macro_rules! impl_euclidean_domain {
+  ($inner:ty) => {
+    impl $crate::rings::EuclideanDomain for $inner {
+      fn euclidean_norm(&self) -> u128 { self.unsigned_abs() as u128 }
+
+      fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        (self.div_euclid(*divisor), self.rem_euclid(*divisor))
+      }
+
+      fn normalize(&self) -> (Self, Self) { if *self < 0 { (-*self, -1) } else { (*self, 1) } }
+    }
+  };
+}
+
+impl_euclidean_domain!(i8);
+impl_euclidean_domain!(i16);
+impl_euclidean_domain!(i32);
+impl_euclidean_domain!(i64);
+impl_euclidean_domain!(i128);
+impl_euclidean_domain!(isize);
+
 /// A trait representing a mathematical semiring.
 ///
 /// A semiring is a set equipped with two binary operations (addition and multiplication)
@@ -18,6 +18,9 @@
 //!   block matrices that show up in tests and in the Sheaf implementation.
 //! * An implementation of the [`crate::category::Category`] trait for the dynamic column vector
 //!   type `DVector<F>` so that *vectors are objects and matrices are morphisms*.
+//! * [`sparse`] – a column-major sparse matrix with sparse column reduction, used for the large
+//!   boundary operators of cell complexes.
+//! * [`smith`] – the Smith normal form of matrices over Euclidean domains such as the integers.
 //!
 //! None of these helpers require the scalar type `F` to implement `ClosedAdd`, `ClosedMul`, … and
 //! therefore remain usable for finite-field types such as the `Mod7` fixture used throughout the
@@ -26,6 +29,9 @@
 pub use nalgebra::*;
 use num_traits::{One, Zero};
 
+pub mod smith;
+pub mod sparse;
+
 /// Computes the **Reduced Row-Echelon Form** (RREF) of the given dynamic matrix using a
 /// plain-Rust Gauss–Jordan elimination that only requires the scalar type `F` to implement the
 /// [`crate::rings::Field`] trait.
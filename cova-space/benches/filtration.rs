@@ -94,6 +94,14 @@ fn bench_filtration_50pts(c: &mut Criterion) {
     })
   });
 
+  let vr_2_skeleton: VietorisRips<
+    2,
+    cova_space::complexes::Complex<cova_space::complexes::Simplex>,
+  > = VietorisRips::new().with_max_dimension(2);
+  group.bench_function("vietoris_rips_build_50pts_max_dim_2", |b| {
+    b.iter(|| black_box(vr_2_skeleton.build(black_box(&cloud), black_box(0.5), black_box(&()))))
+  });
+
   group.finish();
 }
 
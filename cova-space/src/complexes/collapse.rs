@@ -0,0 +1,221 @@
+//! # Elementary Collapses
+//!
+//! This module shrinks a complex to a smaller, homotopy-equivalent subcomplex by repeatedly
+//! removing free faces, which makes subsequent homology computations considerably cheaper.
+//!
+//! ## Mathematical Background
+//!
+//! A cell $\tau$ of a complex $K$ is a **free face** if it is a face of exactly one other cell
+//! $\sigma$, with $\dim \sigma = \dim \tau + 1$ and incidence $[\sigma : \tau] = \pm 1$. Removing
+//! the pair $(\tau, \sigma)$ is an **elementary collapse** $K \searrow K \setminus \{\tau,
+//! \sigma\}$: the result is again a complex, and it is a deformation retract of $K$, so all
+//! homology groups are unchanged. In the chain complex the pair spans an acyclic summand $\sigma
+//! \mapsto \pm\tau$, which is exactly what the collapse removes.
+//!
+//! Collapsing greedily until no free face remains does not always reach a minimal complex (some
+//! contractible complexes such as the dunce hat have no free faces at all), but for the dense
+//! complexes produced by filtrations of point clouds it typically removes the vast majority of
+//! cells.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use cova_algebra::algebras::boolean::Boolean;
+//! use cova_space::complexes::{Simplex, SimplicialComplex};
+//!
+//! // A filled square with a hanging edge collapses to a single point.
+//! let mut complex = SimplicialComplex::new();
+//! complex.join_element(Simplex::new(2, vec![0, 1, 2]));
+//! complex.join_element(Simplex::new(2, vec![1, 2, 3]));
+//! complex.join_element(Simplex::new(1, vec![3, 4]));
+//!
+//! let (collapsed, record) = complex.collapse_with_record();
+//! assert_eq!(collapsed.f_vector(), vec![1]);
+//! assert_eq!(record.len(), 6);
+//! assert_eq!(collapsed.homology::<Boolean>(0).betti_number, 1);
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+use super::*;
+
+/// A single elementary collapse, removing a free face together with its unique coface.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElementaryCollapse<T> {
+  /// The free face $\tau$.
+  pub free_face: T,
+  /// The unique coface $\sigma$ of the free face, of one dimension higher.
+  pub coface:    T,
+}
+
+impl<T: ComplexElement> Complex<T> {
+  /// Collapses the complex greedily along free faces.
+  ///
+  /// Returns a subcomplex that is homotopy equivalent to `self` and has no free faces left.
+  /// Elements of the returned complex keep the IDs they have in `self`. See the
+  /// [module documentation](self) for details.
+  pub fn collapse(&self) -> Self { self.collapse_with_record().0 }
+
+  /// Collapses the complex greedily along free faces, recording every elementary collapse.
+  ///
+  /// The collapses are listed in the order they were performed; replaying them on `self`
+  /// (removing each free face together with its coface) yields the returned complex. Elements in
+  /// the record carry their IDs in `self`.
+  pub fn collapse_with_record(&self) -> (Self, Vec<ElementaryCollapse<T>>) {
+    let mut ids: Vec<usize> = self.elements.keys().copied().collect();
+    ids.sort_unstable();
+
+    let faces: HashMap<usize, Vec<usize>> = ids
+      .iter()
+      .map(|&id| (id, self.faces(&self.elements[&id]).iter().filter_map(T::id).collect()))
+      .collect();
+    let cofaces: HashMap<usize, Vec<usize>> = ids
+      .iter()
+      .map(|&id| (id, self.cofaces(&self.elements[&id]).iter().filter_map(T::id).collect()))
+      .collect();
+
+    let mut alive: HashSet<usize> = ids.iter().copied().collect();
+    let mut coface_count: HashMap<usize, usize> =
+      cofaces.iter().map(|(&id, cofaces)| (id, cofaces.len())).collect();
+    let mut candidates: Vec<usize> =
+      ids.iter().rev().copied().filter(|id| coface_count[id] == 1).collect();
+    let mut record = Vec::new();
+
+    while let Some(tau) = candidates.pop() {
+      if !alive.contains(&tau) || coface_count[&tau] != 1 {
+        continue;
+      }
+      let sigma = *cofaces[&tau]
+        .iter()
+        .find(|id| alive.contains(id))
+        .expect("a free face has exactly one live coface");
+      if coface_count[&sigma] != 0 || !self.is_regular_face(&self.elements[&tau], sigma) {
+        continue;
+      }
+
+      alive.remove(&tau);
+      alive.remove(&sigma);
+      record.push(ElementaryCollapse {
+        free_face: self.elements[&tau].clone(),
+        coface:    self.elements[&sigma].clone(),
+      });
+
+      for face in faces[&sigma].iter().chain(&faces[&tau]) {
+        if !alive.contains(face) {
+          continue;
+        }
+        let count = coface_count.get_mut(face).expect("every element has a coface count");
+        *count -= 1;
+        if *count == 1 {
+          candidates.push(*face);
+        }
+      }
+    }
+
+    (self.subcomplex_from_ids(alive), record)
+  }
+
+  /// Returns whether `face` appears in the boundary of the element with ID `coface` with
+  /// incidence ±1.
+  fn is_regular_face(&self, face: &T, coface: usize) -> bool {
+    let incidence: i32 = self.elements[&coface]
+      .boundary_with_orientations()
+      .iter()
+      .filter(|(boundary_face, _)| boundary_face.same_content(face))
+      .map(|(_, orientation)| orientation)
+      .sum();
+    incidence.abs() == 1
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use cova_algebra::{algebras::boolean::Boolean, tensors::SVector};
+
+  use super::*;
+  use crate::{cloud::Cloud, filtration::vietoris_rips::VietorisRips};
+
+  #[test]
+  fn test_collapse_preserves_homology() {
+    // A sphere has no free faces at all.
+    let mut sphere = SimplicialComplex::new();
+    for face in [[0, 1, 2], [0, 1, 3], [0, 2, 3], [1, 2, 3]] {
+      sphere.join_element(Simplex::new(2, face.to_vec()));
+    }
+    let (collapsed, record) = sphere.collapse_with_record();
+    assert!(record.is_empty());
+    assert_eq!(collapsed.f_vector(), sphere.f_vector());
+
+    // A cone over a circle wedged with a circle collapses onto a circle.
+    let mut circle = SimplicialComplex::new();
+    for edge in [[0, 1], [1, 2], [0, 2]] {
+      circle.join_element(Simplex::new(1, edge.to_vec()));
+    }
+    let complex = circle.cone().wedge(&circle, 0, 0);
+    let collapsed = complex.collapse();
+    assert_eq!(collapsed.f_vector(), vec![3, 3]);
+    assert_eq!(collapsed.betti_numbers::<Boolean>(), vec![1, 1]);
+
+    // Collapsed elements keep their IDs and come from the original complex.
+    for element in collapsed.elements.values() {
+      assert!(complex.get_element(element.id().unwrap()).unwrap().same_content(element));
+    }
+  }
+
+  #[test]
+  fn test_collapse_record() {
+    let mut complex = SimplicialComplex::new();
+    complex.join_element(Simplex::new(3, vec![0, 1, 2, 3]));
+    let (collapsed, record) = complex.collapse_with_record();
+
+    assert_eq!(collapsed.f_vector(), vec![1]);
+    assert_eq!(2 * record.len() + 1, complex.elements.len());
+    for collapse in &record {
+      assert_eq!(collapse.coface.dimension(), collapse.free_face.dimension() + 1);
+      assert!(complex.faces(&collapse.coface).contains(&collapse.free_face));
+    }
+  }
+
+  #[test]
+  fn test_collapse_cubical() {
+    // An annulus of eight squares collapses onto a cycle.
+    let vertex = |i: usize, j: usize| 4 * j + i;
+    let mut annulus = CubicalComplex::new();
+    for j in 0..3 {
+      for i in 0..3 {
+        if (i, j) != (1, 1) {
+          annulus.join_element(Cube::square([
+            vertex(i, j),
+            vertex(i + 1, j),
+            vertex(i, j + 1),
+            vertex(i + 1, j + 1),
+          ]));
+        }
+      }
+    }
+    let collapsed = annulus.collapse();
+    assert!(collapsed.elements_of_dimension(2).is_empty());
+    assert_eq!(collapsed.euler_characteristic(), 0);
+    assert_eq!(collapsed.betti_numbers::<Boolean>(), vec![1, 1]);
+  }
+
+  #[test]
+  fn test_collapse_rips_complex() {
+    // Points on two concentric rings sampled densely enough to fill in the annulus between them.
+    let points: Vec<_> = (0..24)
+      .map(|i| {
+        let angle = std::f64::consts::TAU * f64::from(i % 12) / 12.0;
+        let radius = if i < 12 { 1.0 } else { 1.4 };
+        SVector::from([radius * angle.cos(), radius * angle.sin()])
+      })
+      .collect();
+    let cloud = Cloud::new(points);
+    let rips = VietorisRips::<2, SimplicialComplex>::new();
+    let complex = rips.build_complex(&cloud, 0.4);
+
+    let collapsed = complex.collapse();
+    assert!(collapsed.elements.len() < complex.elements.len() / 4);
+    assert_eq!(collapsed.betti_numbers::<Boolean>(), vec![1, 1]);
+    assert_eq!(complex.betti_numbers::<Boolean>(), vec![1, 1, 0, 0]);
+  }
+}
@@ -0,0 +1,453 @@
+//! # Constructions on Complexes
+//!
+//! This module assembles new complexes from existing ones with the standard constructions of
+//! algebraic topology. They are the quickest way to build test spaces with known homology, e.g.
+//! spheres as iterated suspensions or tori as products of circles.
+//!
+//! - Simplicial complexes: [`cone`](Complex::cone), [`suspension`](Complex::suspension),
+//!   [`join`](Complex::join), `wedge`, `disjoint_union` and `product`.
+//! - Cubical complexes: `wedge`, `disjoint_union` and `product`.
+//!
+//! ## Mathematical Background
+//!
+//! - The **join** $K * L$ has a simplex $\sigma \sqcup \tau$ for every pair of simplices $\sigma
+//!   \in K \cup \{\emptyset\}$ and $\tau \in L \cup \{\emptyset\}$. The **cone** $CK = K * \{a\}$
+//!   is contractible, and the **suspension** $\Sigma K = K * \{n, s\}$ shifts reduced homology up
+//!   by one: $\tilde H_{k+1}(\Sigma K) \cong \tilde H_k(K)$.
+//! - The **wedge sum** $K \vee L$ glues $K$ and $L$ at a single vertex, so its reduced homology is
+//!   $\tilde H_k(K) \oplus \tilde H_k(L)$. The **disjoint union** places them side by side.
+//! - The **product** of cubical complexes is again cubical: a $p$-cube times a $q$-cube is a
+//!   $(p+q)$-cube. A product of simplices is not a simplex, so the simplicial product uses the
+//!   **staircase triangulation**: with vertices of $\sigma$ and $\tau$ ordered by label, $\sigma
+//!   \times \tau$ is covered by one $(p+q)$-simplex for every monotone lattice path from $(0, 0)$
+//!   to $(p, q)$, giving $\binom{p+q}{p}$ simplices. By the Künneth formula, homology over a field
+//!   satisfies $H_n(K \times L) \cong \bigoplus_{i+j=n} H_i(K) \otimes H_j(L)$.
+//!
+//! ## Vertex Labels
+//!
+//! Except in products, the vertices of `self` keep their labels and vertices added by a
+//! construction get labels larger than every label of `self`: for two-complex constructions, the
+//! labels of `other` are shifted by one more than the largest label of `self`. In a product, the
+//! vertex $(v, w)$ gets the label $v \cdot (m + 1) + w$, where $m$ is the largest label of
+//! `other`.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use cova_algebra::algebras::boolean::Boolean;
+//! use cova_space::complexes::{Simplex, SimplicialComplex};
+//!
+//! let mut circle = SimplicialComplex::new();
+//! for edge in [[0, 1], [1, 2], [0, 2]] {
+//!   circle.join_element(Simplex::new(1, edge.to_vec()));
+//! }
+//!
+//! // The suspension of a circle is a 2-sphere.
+//! let sphere = circle.suspension();
+//! assert_eq!(sphere.homology::<Boolean>(2).betti_number, 1);
+//!
+//! // The product of two circles is a torus.
+//! let torus = circle.product(&circle);
+//! assert_eq!(torus.homology::<Boolean>(1).betti_number, 2);
+//! assert_eq!(torus.homology::<Boolean>(2).betti_number, 1);
+//! ```
+
+use super::*;
+
+impl<T: ComplexElement> Complex<T> {
+  /// Returns the elements that are not a face of any other element.
+  fn maximal_elements(&self) -> Vec<T> {
+    let mut maximal: Vec<T> =
+      self.elements.values().filter(|element| self.cofaces(element).is_empty()).cloned().collect();
+    maximal.sort_unstable();
+    maximal
+  }
+
+  /// Adds the maximal elements of `other`, transformed by `relabel`, to a copy of `self`.
+  fn extended_by(&self, other: &Self, relabel: impl Fn(&T) -> T) -> Self {
+    let mut result = self.clone();
+    for element in other.maximal_elements() {
+      result.join_element(relabel(&element));
+    }
+    result
+  }
+}
+
+/// Returns the largest vertex label of a complex whose elements are given by vertex labels.
+fn max_label<'a>(labels: impl Iterator<Item = &'a [usize]>) -> Option<usize> {
+  labels.flat_map(|vertices| vertices.iter().copied()).max()
+}
+
+impl Complex<Simplex> {
+  /// Returns the largest vertex label, or `None` for an empty complex.
+  fn max_vertex_label(&self) -> Option<usize> {
+    max_label(self.elements.values().map(Simplex::vertices))
+  }
+
+  /// Returns the offset added to the labels of a second complex to keep them apart from `self`.
+  fn label_offset(&self) -> usize { self.max_vertex_label().map_or(0, |max| max + 1) }
+
+  /// Computes the cone over the complex.
+  ///
+  /// The apex is a new vertex labelled one more than the largest label of `self` (or `0` for an
+  /// empty complex, whose cone is a single point). The cone is always contractible.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use cova_space::complexes::{Simplex, SimplicialComplex};
+  ///
+  /// let mut edge = SimplicialComplex::new();
+  /// edge.join_element(Simplex::new(1, vec![0, 1]));
+  ///
+  /// // The cone over an edge is a triangle with apex 2.
+  /// let triangle = edge.cone();
+  /// assert_eq!(triangle.f_vector(), vec![3, 3, 1]);
+  /// ```
+  pub fn cone(&self) -> Self { self.join(&Self::points(1)) }
+
+  /// Computes the (unreduced) suspension of the complex: the join with two points.
+  ///
+  /// The two suspension points are labelled one and two more than the largest label of `self`.
+  pub fn suspension(&self) -> Self { self.join(&Self::points(2)) }
+
+  /// Computes the join of two simplicial complexes.
+  ///
+  /// The result contains both complexes together with the simplex spanned by $\sigma \cup \tau$
+  /// for every pair of simplices $\sigma$ of `self` and $\tau$ of `other`. Vertex labels of `other`
+  /// are shifted as described in the [module documentation](self).
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use cova_algebra::algebras::boolean::Boolean;
+  /// use cova_space::complexes::{Simplex, SimplicialComplex};
+  ///
+  /// // Two 0-spheres join to a circle made of four edges.
+  /// let mut points = SimplicialComplex::new();
+  /// points.join_element(Simplex::new(0, vec![0]));
+  /// points.join_element(Simplex::new(0, vec![1]));
+  ///
+  /// let circle = points.join(&points);
+  /// assert_eq!(circle.f_vector(), vec![4, 4]);
+  /// assert_eq!(circle.homology::<Boolean>(1).betti_number, 1);
+  /// ```
+  pub fn join(&self, other: &Self) -> Self {
+    let offset = self.label_offset();
+    let shift = |simplex: &Simplex| -> Vec<usize> {
+      simplex.vertices().iter().map(|vertex| vertex + offset).collect()
+    };
+
+    let (ours, theirs) = (self.maximal_elements(), other.maximal_elements());
+    if ours.is_empty() || theirs.is_empty() {
+      return self.extended_by(other, |simplex| Simplex::from_vertices(shift(simplex)));
+    }
+    let mut result = Self::new();
+    for sigma in &ours {
+      for tau in &theirs {
+        let mut vertices = sigma.vertices().to_vec();
+        vertices.extend(shift(tau));
+        result.join_element(Simplex::from_vertices(vertices));
+      }
+    }
+    result
+  }
+
+  /// Computes the disjoint union of two simplicial complexes.
+  ///
+  /// Vertex labels of `other` are shifted as described in the [module documentation](self).
+  pub fn disjoint_union(&self, other: &Self) -> Self {
+    let offset = self.label_offset();
+    self.extended_by(other, |simplex| {
+      Simplex::from_vertices(simplex.vertices().iter().map(|vertex| vertex + offset).collect())
+    })
+  }
+
+  /// Computes the wedge sum of two simplicial complexes, gluing the vertex `other_base` of `other`
+  /// to the vertex `base` of `self`.
+  ///
+  /// The remaining vertex labels of `other` are shifted as described in the
+  /// [module documentation](self).
+  ///
+  /// # Panics
+  ///
+  /// Panics if `base` is not a vertex of `self` or `other_base` is not a vertex of `other`.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use cova_algebra::algebras::boolean::Boolean;
+  /// use cova_space::complexes::{Simplex, SimplicialComplex};
+  ///
+  /// let mut circle = SimplicialComplex::new();
+  /// for edge in [[0, 1], [1, 2], [0, 2]] {
+  ///   circle.join_element(Simplex::new(1, edge.to_vec()));
+  /// }
+  ///
+  /// let figure_eight = circle.wedge(&circle, 0, 0);
+  /// assert_eq!(figure_eight.f_vector(), vec![5, 6]);
+  /// assert_eq!(figure_eight.homology::<Boolean>(1).betti_number, 2);
+  /// ```
+  pub fn wedge(&self, other: &Self, base: usize, other_base: usize) -> Self {
+    assert!(self.contains_vertex(base), "{base} is not a vertex of the first complex");
+    assert!(
+      other.contains_vertex(other_base),
+      "{other_base} is not a vertex of the second complex"
+    );
+    let offset = self.label_offset();
+    let relabel = |vertex: usize| if vertex == other_base { base } else { vertex + offset };
+    self.extended_by(other, |simplex| {
+      Simplex::from_vertices(simplex.vertices().iter().map(|vertex| relabel(*vertex)).collect())
+    })
+  }
+
+  /// Computes the product of two simplicial complexes using the staircase triangulation.
+  ///
+  /// Vertex labels are assigned as described in the [module documentation](self). The product of
+  /// a $p$-simplex and a $q$-simplex is split into $\binom{p+q}{p}$ simplices of dimension $p+q$.
+  pub fn product(&self, other: &Self) -> Self {
+    let stride = other.label_offset();
+    let mut result = Self::new();
+    for sigma in self.maximal_elements() {
+      for tau in other.maximal_elements() {
+        let (p, q) = (sigma.dimension(), tau.dimension());
+        for path in staircase_paths(p, q) {
+          let (mut i, mut j) = (0, 0);
+          let mut vertices = vec![sigma.vertices()[0] * stride + tau.vertices()[0]];
+          for step_right in path {
+            if step_right {
+              i += 1;
+            } else {
+              j += 1;
+            }
+            vertices.push(sigma.vertices()[i] * stride + tau.vertices()[j]);
+          }
+          result.join_element(Simplex::from_vertices(vertices));
+        }
+      }
+    }
+    result
+  }
+
+  /// Returns whether `vertex` is a vertex label of the complex.
+  fn contains_vertex(&self, vertex: usize) -> bool {
+    self.find_equivalent_element(&Simplex::new(0, vec![vertex])).is_some()
+  }
+
+  /// Returns the complex consisting of the isolated vertices `0..count`.
+  fn points(count: usize) -> Self {
+    let mut points = Self::new();
+    for vertex in 0..count {
+      points.join_element(Simplex::new(0, vec![vertex]));
+    }
+    points
+  }
+}
+
+/// Returns all monotone lattice paths from `(0, 0)` to `(p, q)`, as sequences of steps where
+/// `true` increments the first coordinate and `false` the second.
+fn staircase_paths(p: usize, q: usize) -> Vec<Vec<bool>> {
+  if p == 0 || q == 0 {
+    return vec![vec![p > 0; p + q]];
+  }
+  let mut paths = Vec::new();
+  for (first, rest) in [(true, staircase_paths(p - 1, q)), (false, staircase_paths(p, q - 1))] {
+    for path in rest {
+      let mut full = vec![first];
+      full.extend(path);
+      paths.push(full);
+    }
+  }
+  paths
+}
+
+impl Complex<Cube> {
+  /// Returns the offset added to the labels of a second complex to keep them apart from `self`.
+  fn label_offset(&self) -> usize {
+    max_label(self.elements.values().map(Cube::vertices)).map_or(0, |max| max + 1)
+  }
+
+  /// Computes the disjoint union of two cubical complexes.
+  ///
+  /// Vertex labels of `other` are shifted as described in the [module documentation](self).
+  pub fn disjoint_union(&self, other: &Self) -> Self {
+    let offset = self.label_offset();
+    self.extended_by(other, |cube| {
+      Cube::new(cube.dimension(), cube.vertices().iter().map(|vertex| vertex + offset).collect())
+    })
+  }
+
+  /// Computes the wedge sum of two cubical complexes, gluing the vertex `other_base` of `other`
+  /// to the vertex `base` of `self`.
+  ///
+  /// The remaining vertex labels of `other` are shifted as described in the
+  /// [module documentation](self).
+  ///
+  /// # Panics
+  ///
+  /// Panics if `base` is not a vertex of `self` or `other_base` is not a vertex of `other`.
+  pub fn wedge(&self, other: &Self, base: usize, other_base: usize) -> Self {
+    assert!(
+      self.find_equivalent_element(&Cube::vertex(base)).is_some(),
+      "{base} is not a vertex of the first complex"
+    );
+    assert!(
+      other.find_equivalent_element(&Cube::vertex(other_base)).is_some(),
+      "{other_base} is not a vertex of the second complex"
+    );
+    let offset = self.label_offset();
+    let relabel = |vertex: usize| if vertex == other_base { base } else { vertex + offset };
+    self.extended_by(other, |cube| {
+      Cube::new(cube.dimension(), cube.vertices().iter().map(|vertex| relabel(*vertex)).collect())
+    })
+  }
+
+  /// Computes the product of two cubical complexes.
+  ///
+  /// The product of a $p$-cube $\sigma$ and a $q$-cube $\tau$ is the $(p+q)$-cube whose first $p$
+  /// coordinate axes are those of $\sigma$ and whose last $q$ axes are those of $\tau$. Vertex
+  /// labels are assigned as described in the [module documentation](self).
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use cova_algebra::algebras::boolean::Boolean;
+  /// use cova_space::complexes::{Cube, CubicalComplex};
+  ///
+  /// let mut circle = CubicalComplex::new();
+  /// for i in 0..4 {
+  ///   circle.join_element(Cube::edge(i, (i + 1) % 4));
+  /// }
+  ///
+  /// let torus = circle.product(&circle);
+  /// assert_eq!(torus.f_vector(), vec![16, 32, 16]);
+  /// assert_eq!(torus.homology::<Boolean>(1).betti_number, 2);
+  /// ```
+  pub fn product(&self, other: &Self) -> Self {
+    let stride = other.label_offset();
+    let mut result = Self::new();
+    for sigma in self.maximal_elements() {
+      for tau in other.maximal_elements() {
+        let vertices = tau
+          .vertices()
+          .iter()
+          .flat_map(|w| sigma.vertices().iter().map(move |v| v * stride + w))
+          .collect();
+        result.join_element(Cube::new(sigma.dimension() + tau.dimension(), vertices));
+      }
+    }
+    result
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use cova_algebra::algebras::boolean::Boolean;
+
+  use super::*;
+
+  fn simplicial_circle() -> SimplicialComplex {
+    let mut circle = SimplicialComplex::new();
+    for edge in [[0, 1], [1, 2], [0, 2]] {
+      circle.join_element(Simplex::new(1, edge.to_vec()));
+    }
+    circle
+  }
+
+  fn cubical_circle() -> CubicalComplex {
+    let mut circle = CubicalComplex::new();
+    for i in 0..4 {
+      circle.join_element(Cube::edge(i, (i + 1) % 4));
+    }
+    circle
+  }
+
+  #[test]
+  fn test_cone_and_suspension() {
+    let circle = simplicial_circle();
+
+    let cone = circle.cone();
+    assert_eq!(cone.f_vector(), vec![4, 6, 3]);
+    assert_eq!(cone.betti_numbers::<Boolean>(), vec![1, 0, 0]);
+
+    // Iterated suspensions of a circle are spheres of increasing dimension.
+    let s2 = circle.suspension();
+    assert_eq!(s2.betti_numbers::<Boolean>(), vec![1, 0, 1]);
+    let s3 = s2.suspension();
+    assert_eq!(s3.betti_numbers::<Boolean>(), vec![1, 0, 0, 1]);
+    assert_eq!(s3.euler_characteristic(), 0);
+
+    assert_eq!(SimplicialComplex::new().cone().f_vector(), vec![1]);
+  }
+
+  #[test]
+  fn test_join() {
+    // The join of two circles is a 3-sphere.
+    let circle = simplicial_circle();
+    let s3 = circle.join(&circle);
+    assert_eq!(s3.f_vector(), vec![6, 15, 18, 9]);
+    assert_eq!(s3.betti_numbers::<Boolean>(), vec![1, 0, 0, 1]);
+
+    // Joining with the empty complex changes nothing but the labels.
+    assert_eq!(circle.join(&SimplicialComplex::new()).f_vector(), circle.f_vector());
+    assert_eq!(SimplicialComplex::new().join(&circle).f_vector(), circle.f_vector());
+  }
+
+  #[test]
+  fn test_wedge_and_disjoint_union() {
+    let sphere = simplicial_circle().suspension();
+    let circle = simplicial_circle();
+
+    let wedge = sphere.wedge(&circle, 3, 0);
+    assert_eq!(wedge.f_vector()[0], 5 + 3 - 1);
+    assert_eq!(wedge.betti_numbers::<Boolean>(), vec![1, 1, 1]);
+
+    let union = sphere.disjoint_union(&circle);
+    assert_eq!(union.betti_numbers::<Boolean>(), vec![2, 1, 1]);
+    assert_eq!(union.euler_characteristic(), sphere.euler_characteristic());
+
+    let cubical = cubical_circle();
+    assert_eq!(cubical.wedge(&cubical, 0, 2).betti_numbers::<Boolean>(), vec![1, 2]);
+    assert_eq!(cubical.disjoint_union(&cubical).betti_numbers::<Boolean>(), vec![2, 2]);
+  }
+
+  #[test]
+  #[should_panic(expected = "is not a vertex of the second complex")]
+  fn test_wedge_requires_base_vertices() { simplicial_circle().wedge(&simplicial_circle(), 0, 7); }
+
+  #[test]
+  fn test_simplicial_product() {
+    assert_eq!(staircase_paths(2, 1).len(), 3);
+    assert_eq!(staircase_paths(0, 2), vec![vec![false, false]]);
+
+    // A triangle times an edge is a prism split into three tetrahedra.
+    let mut triangle = SimplicialComplex::new();
+    triangle.join_element(Simplex::new(2, vec![0, 1, 2]));
+    let mut edge = SimplicialComplex::new();
+    edge.join_element(Simplex::new(1, vec![0, 1]));
+    let prism = triangle.product(&edge);
+    assert_eq!(prism.f_vector(), vec![6, 12, 10, 3]);
+    assert_eq!(prism.betti_numbers::<Boolean>(), vec![1, 0, 0, 0]);
+
+    // Künneth: the circle times the 2-sphere has Betti numbers (1, 1, 1, 1).
+    let circle = simplicial_circle();
+    let product = circle.product(&circle.suspension());
+    assert_eq!(product.betti_numbers::<Boolean>(), vec![1, 1, 1, 1]);
+  }
+
+  #[test]
+  fn test_cubical_product() {
+    let circle = cubical_circle();
+    let torus = circle.product(&circle);
+    assert_eq!(torus.f_vector(), vec![16, 32, 16]);
+    assert_eq!(torus.betti_numbers::<Boolean>(), vec![1, 2, 1]);
+
+    let mut interval = CubicalComplex::new();
+    interval.join_element(Cube::edge(0, 1));
+    let cylinder = circle.product(&interval);
+    assert_eq!(cylinder.betti_numbers::<Boolean>(), vec![1, 1, 0]);
+
+    let three_torus = torus.product(&circle);
+    assert_eq!(three_torus.betti_numbers::<Boolean>(), vec![1, 3, 3, 1]);
+  }
+}
@@ -122,6 +122,9 @@ impl fmt::Display for Cube {
 }
 
 impl ComplexElement for Cube {
+  /// The dimension together with the vertex list.
+  type Content = (usize, Vec<usize>);
+
   fn dimension(&self) -> usize { self.dimension }
 
   fn faces(&self) -> Vec<Self> {
@@ -194,6 +197,8 @@ impl ComplexElement for Cube {
 
   fn id(&self) -> Option<usize> { self.id }
 
+  fn content(&self) -> Self::Content { (self.dimension, self.vertices.clone()) }
+
   fn same_content(&self, other: &Self) -> bool { self.same_content(other) }
 
   fn with_id(&self, new_id: usize) -> Self { self.clone().with_id(new_id) }
@@ -0,0 +1,496 @@
+//! # Elementary Cubes and Cubical Sets
+//!
+//! This module provides cubes described by **grid coordinates** rather than vertex lists, following
+//! Kaczynski, Mischaikow and Mrozek, *Computational Homology*. Unlike [`Cube`], whose faces and
+//! orientations depend on the order in which the caller lists its vertices, an
+//! [`ElementaryCube`] is always a genuine cube of the integer lattice and its boundary is correct
+//! by construction.
+//!
+//! ## Mathematical Background
+//!
+//! An **elementary interval** is either a degenerate interval $[k] = [k, k]$ or a unit interval
+//! $[k, k + 1]$ with $k \in \mathbb{Z}$. An **elementary cube** in $\mathbb{R}^d$ is a product
+//! $$ Q = I_1 \times I_2 \times \dots \times I_d $$
+//! of elementary intervals. It is determined by its **anchor** (the lower endpoints of the
+//! $I_i$) together with its set of **free directions** (the axes along which $I_i$ is
+//! non-degenerate), and its dimension is the number of free directions.
+//!
+//! The boundary of an elementary interval is $\partial [k, k + 1] = [k + 1] - [k]$, and the
+//! boundary of a product follows the Leibniz rule. Writing $Q^{\pm}_i$ for the face obtained by
+//! replacing the $i$-th free interval $[k, k + 1]$ with $[k + 1]$ or $[k]$,
+//! $$ \partial Q = \sum_{i} (-1)^{m_i} \left( Q^{+}_i - Q^{-}_i \right), $$
+//! where $m_i$ is the number of free directions before the $i$-th one.
+//!
+//! ## Cubical Sets
+//!
+//! A [`CubicalSet`] is a finite set of elementary cubes closed under taking faces. It stores its
+//! cubes in hash-indexed lists per dimension and builds boundary matrices directly from the
+//! formula above, with none of the bookkeeping of the general [`Complex`]. This makes
+//! [`CubicalSet::homology`] the fast path for large cubical data such as binary images, while
+//! [`CubicalSet::to_complex`] converts to a [`Complex`] when the generic machinery is needed.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use cova_algebra::algebras::boolean::Boolean;
+//! use cova_space::complexes::elementary::{CubicalSet, ElementaryCube};
+//!
+//! // The square [0, 1] × [0, 1] has the four edges as its faces.
+//! let square = ElementaryCube::from_intervals(&[(0, 1), (0, 1)]);
+//! assert_eq!(square.dimension(), 2);
+//!
+//! // A ring of eight unit squares around a missing centre square.
+//! let ring: CubicalSet = (0..3)
+//!   .flat_map(|x| (0..3).map(move |y| vec![x, y]))
+//!   .filter(|anchor| anchor != &vec![1, 1])
+//!   .map(|anchor| ElementaryCube::new(anchor, vec![true, true]))
+//!   .collect();
+//!
+//! assert_eq!(ring.homology::<Boolean>(0).betti_number, 1);
+//! assert_eq!(ring.homology::<Boolean>(1).betti_number, 1);
+//! ```
+
+use std::{collections::HashMap, fmt};
+
+use cova_algebra::{
+  rings::Field,
+  tensors::sparse::{SparseMatrix, kernel_modulo_image},
+};
+
+use super::{Complex, ComplexElement, Cube};
+use crate::homology::Homology;
+
+/// An elementary cube: a product of elementary intervals in $\mathbb{Z}^d$.
+///
+/// The cube is stored as the lower corner of each interval (the anchor) and a flag per axis
+/// recording whether the interval along that axis is a unit interval (free) or a single point.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ElementaryCube {
+  /// The lower endpoint of the interval along each axis.
+  anchor: Vec<i64>,
+  /// Whether the interval along each axis is the unit interval `[k, k + 1]`.
+  free:   Vec<bool>,
+  /// An optional unique identifier assigned when the cube is added to a complex.
+  id:     Option<usize>,
+}
+
+impl ElementaryCube {
+  /// Creates the elementary cube with the given anchor and free directions.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `anchor` and `free` have different lengths.
+  pub fn new(anchor: Vec<i64>, free: Vec<bool>) -> Self {
+    assert_eq!(
+      anchor.len(),
+      free.len(),
+      "an elementary cube needs one free-direction flag per coordinate"
+    );
+    Self { anchor, free, id: None }
+  }
+
+  /// Creates the $0$-dimensional cube at a grid point.
+  pub fn vertex(point: Vec<i64>) -> Self {
+    let free = vec![false; point.len()];
+    Self::new(point, free)
+  }
+
+  /// Creates the product of the intervals `[a, b]`, each of which must be elementary.
+  ///
+  /// # Panics
+  ///
+  /// Panics if some interval has `b - a` other than `0` or `1`.
+  pub fn from_intervals(intervals: &[(i64, i64)]) -> Self {
+    let (anchor, free) = intervals
+      .iter()
+      .map(|&(a, b)| {
+        assert!(b == a || b == a + 1, "[{a}, {b}] is not an elementary interval");
+        (a, b == a + 1)
+      })
+      .unzip();
+    Self::new(anchor, free)
+  }
+
+  /// Returns the lower endpoint of the interval along each axis.
+  pub fn anchor(&self) -> &[i64] { &self.anchor }
+
+  /// Returns, for each axis, whether the cube extends along it.
+  pub fn free(&self) -> &[bool] { &self.free }
+
+  /// Returns the axes along which the cube extends, in increasing order.
+  pub fn free_directions(&self) -> impl Iterator<Item = usize> + '_ {
+    self.free.iter().enumerate().filter(|(_, free)| **free).map(|(axis, _)| axis)
+  }
+
+  /// Returns the dimension of the cube, i.e. its number of free directions.
+  pub fn dimension(&self) -> usize { self.free.iter().filter(|&&free| free).count() }
+
+  /// Returns the dimension $d$ of the space $\mathbb{Z}^d$ the cube lives in.
+  pub fn embedding_dimension(&self) -> usize { self.anchor.len() }
+
+  /// Returns the intervals `[a, b]` whose product is the cube.
+  pub fn intervals(&self) -> Vec<(i64, i64)> {
+    self.anchor.iter().zip(&self.free).map(|(&a, &free)| (a, a + i64::from(free))).collect()
+  }
+
+  /// Returns the ID of the cube if it has been assigned to a complex.
+  pub const fn id(&self) -> Option<usize> { self.id }
+
+  /// Returns the $2^k$ corners of the cube in binary coordinate order, with the lowest bit along
+  /// the first free direction.
+  ///
+  /// [`ScalarGrid`](crate::filtration::cubical::ScalarGrid) instead puts the lowest bit along the
+  /// last axis, so an elementary cube describing a grid cell should list the grid axes in reverse
+  /// order for [`ElementaryCube::to_cube`] to reproduce the grid's cubes.
+  pub fn corners(&self) -> Vec<Vec<i64>> {
+    let directions: Vec<usize> = self.free_directions().collect();
+    (0..1_usize << directions.len())
+      .map(|corner| {
+        let mut point = self.anchor.clone();
+        for (bit, &axis) in directions.iter().enumerate() {
+          point[axis] += i64::from(corner >> bit & 1 == 1);
+        }
+        point
+      })
+      .collect()
+  }
+
+  /// Converts the cube to a vertex-list [`Cube`], labelling each corner with `label`.
+  ///
+  /// The corners are listed in the order returned by [`ElementaryCube::corners`], so the faces and
+  /// orientations of the resulting [`Cube`] match those of this cube.
+  pub fn to_cube(&self, label: impl Fn(&[i64]) -> usize) -> Cube {
+    Cube::new(self.dimension(), self.corners().iter().map(|point| label(point)).collect())
+  }
+
+  /// Returns the two faces obtained by collapsing the free direction `axis`, paired with their
+  /// incidence numbers.
+  fn collapse(&self, axis: usize, preceding_free: usize) -> [(Self, i32); 2] {
+    let sign = if preceding_free.is_multiple_of(2) { 1 } else { -1 };
+    let mut lower = self.free.clone();
+    lower[axis] = false;
+    let mut upper_anchor = self.anchor.clone();
+    upper_anchor[axis] += 1;
+    [(Self::new(self.anchor.clone(), lower.clone()), -sign), (Self::new(upper_anchor, lower), sign)]
+  }
+}
+
+impl PartialOrd for ElementaryCube {
+  fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> { Some(self.cmp(other)) }
+}
+
+impl Ord for ElementaryCube {
+  fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    self
+      .dimension()
+      .cmp(&other.dimension())
+      .then_with(|| self.anchor.cmp(&other.anchor))
+      .then_with(|| self.free.cmp(&other.free))
+  }
+}
+
+impl fmt::Display for ElementaryCube {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let intervals: Vec<String> = self
+      .intervals()
+      .into_iter()
+      .map(|(a, b)| if a == b { format!("[{a}]") } else { format!("[{a},{b}]") })
+      .collect();
+    write!(f, "{}", intervals.join("x"))
+  }
+}
+
+impl ComplexElement for ElementaryCube {
+  /// The anchor together with the free-direction flags.
+  type Content = (Vec<i64>, Vec<bool>);
+
+  fn dimension(&self) -> usize { self.dimension() }
+
+  fn faces(&self) -> Vec<Self> {
+    self.boundary_with_orientations().into_iter().map(|(face, _)| face).collect()
+  }
+
+  fn boundary_with_orientations(&self) -> Vec<(Self, i32)> {
+    self
+      .free_directions()
+      .enumerate()
+      .flat_map(|(preceding_free, axis)| self.collapse(axis, preceding_free))
+      .collect()
+  }
+
+  fn id(&self) -> Option<usize> { self.id }
+
+  fn content(&self) -> Self::Content { (self.anchor.clone(), self.free.clone()) }
+
+  fn same_content(&self, other: &Self) -> bool {
+    self.anchor == other.anchor && self.free == other.free
+  }
+
+  fn with_id(&self, new_id: usize) -> Self { Self { id: Some(new_id), ..self.clone() } }
+}
+
+/// A finite set of elementary cubes closed under taking faces.
+///
+/// Cubes of each dimension are kept in insertion order, which is the basis used by
+/// [`CubicalSet::boundary_matrix`] and by the generators returned from [`CubicalSet::homology`].
+#[derive(Debug, Clone, Default)]
+pub struct CubicalSet {
+  /// The cubes of each dimension, in insertion order.
+  cubes: Vec<Vec<ElementaryCube>>,
+  /// The position of each cube within its dimension.
+  index: HashMap<ElementaryCube, usize>,
+}
+
+impl CubicalSet {
+  /// Creates an empty cubical set.
+  pub fn new() -> Self { Self::default() }
+
+  /// Creates the cubical set of the full-dimensional unit cubes with the given anchors, e.g. the
+  /// foreground pixels of a binary image.
+  pub fn from_voxels(anchors: impl IntoIterator<Item = Vec<i64>>) -> Self {
+    anchors
+      .into_iter()
+      .map(|anchor| {
+        let free = vec![true; anchor.len()];
+        ElementaryCube::new(anchor, free)
+      })
+      .collect()
+  }
+
+  /// Inserts a cube together with all of its faces. Returns `false` if it was already present.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the cube lives in a different $\mathbb{Z}^d$ from the cubes already present.
+  pub fn insert(&mut self, cube: ElementaryCube) -> bool {
+    let cube = ElementaryCube { id: None, ..cube };
+    if let Some(existing) = self.index.keys().next() {
+      assert_eq!(
+        existing.embedding_dimension(),
+        cube.embedding_dimension(),
+        "all cubes of a cubical set must have the same embedding dimension"
+      );
+    }
+    if self.index.contains_key(&cube) {
+      return false;
+    }
+
+    let mut stack = vec![cube];
+    while let Some(current) = stack.pop() {
+      if self.index.contains_key(&current) {
+        continue;
+      }
+      let dimension = current.dimension();
+      if self.cubes.len() <= dimension {
+        self.cubes.resize_with(dimension + 1, Vec::new);
+      }
+      stack.extend(current.faces());
+      self.index.insert(current.clone(), self.cubes[dimension].len());
+      self.cubes[dimension].push(current);
+    }
+    true
+  }
+
+  /// Returns `true` if the set contains the given cube.
+  pub fn contains(&self, cube: &ElementaryCube) -> bool {
+    self.index.contains_key(&ElementaryCube { id: None, ..cube.clone() })
+  }
+
+  /// Returns the cubes of dimension `k`, in basis order.
+  pub fn cubes(&self, k: usize) -> &[ElementaryCube] {
+    self.cubes.get(k).map_or(&[], Vec::as_slice)
+  }
+
+  /// Returns the total number of cubes.
+  pub fn len(&self) -> usize { self.index.len() }
+
+  /// Returns `true` if the set contains no cubes.
+  pub fn is_empty(&self) -> bool { self.index.is_empty() }
+
+  /// Returns the largest dimension of a cube in the set, or 0 if it is empty.
+  pub fn max_dimension(&self) -> usize { self.cubes.len().saturating_sub(1) }
+
+  /// Returns the number of cubes in each dimension.
+  pub fn f_vector(&self) -> Vec<usize> { self.cubes.iter().map(Vec::len).collect() }
+
+  /// Returns the Euler characteristic χ = Σₖ (-1)ᵏ fₖ.
+  pub fn euler_characteristic(&self) -> isize {
+    self
+      .f_vector()
+      .into_iter()
+      .enumerate()
+      .map(|(k, count)| if k % 2 == 0 { count as isize } else { -(count as isize) })
+      .sum()
+  }
+
+  /// Returns the boundary matrix ∂ₖ: Cₖ → Cₖ₋₁ in the bases given by [`CubicalSet::cubes`].
+  pub fn boundary_matrix<F: Field + Copy>(&self, k: usize) -> SparseMatrix<F> {
+    let nrows = if k == 0 { 0 } else { self.cubes(k - 1).len() };
+    let columns = self
+      .cubes(k)
+      .iter()
+      .map(|cube| {
+        cube
+          .boundary_with_orientations()
+          .into_iter()
+          .map(|(face, sign)| (self.index[&face], if sign > 0 { F::one() } else { -F::one() }))
+          .collect()
+      })
+      .collect();
+    SparseMatrix::from_columns(nrows, columns)
+  }
+
+  /// Computes $H_k$ over the field `F`.
+  ///
+  /// Generators are coefficient vectors in the basis of [`CubicalSet::cubes`]`(k)`.
+  pub fn homology<F: Field + Copy>(&self, k: usize) -> Homology<F> {
+    if self.cubes(k).is_empty() {
+      return Homology::trivial(k);
+    }
+    let generators = kernel_modulo_image(&self.boundary_matrix(k), &self.boundary_matrix(k + 1));
+    Homology {
+      dimension:           k,
+      betti_number:        generators.len(),
+      homology_generators: generators,
+    }
+  }
+
+  /// Converts the set to a general [`Complex`] of elementary cubes.
+  pub fn to_complex(&self) -> Complex<ElementaryCube> {
+    let mut complex = Complex::new();
+    for cube in self.cubes.iter().flatten() {
+      complex.join_element(cube.clone());
+    }
+    complex
+  }
+}
+
+impl FromIterator<ElementaryCube> for CubicalSet {
+  fn from_iter<I: IntoIterator<Item = ElementaryCube>>(iter: I) -> Self {
+    let mut set = Self::new();
+    for cube in iter {
+      set.insert(cube);
+    }
+    set
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use cova_algebra::{algebras::boolean::Boolean, modular, prime_field};
+
+  use super::*;
+  use crate::homology::Chain;
+
+  modular!(Mod7, u32, 7);
+  prime_field!(Mod7);
+
+  #[test]
+  fn test_elementary_cube_structure() {
+    let cube = ElementaryCube::from_intervals(&[(0, 1), (2, 2), (-1, 0)]);
+    assert_eq!(cube.dimension(), 2);
+    assert_eq!(cube.embedding_dimension(), 3);
+    assert_eq!(cube.free_directions().collect::<Vec<_>>(), vec![0, 2]);
+    assert_eq!(cube.intervals(), vec![(0, 1), (2, 2), (-1, 0)]);
+    assert_eq!(cube.to_string(), "[0,1]x[2]x[-1,0]");
+    assert_eq!(cube.corners(), vec![vec![0, 2, -1], vec![1, 2, -1], vec![0, 2, 0], vec![1, 2, 0]]);
+
+    // ∂([0,1] × [0,1]) = [1]×[0,1] − [0]×[0,1] − [0,1]×[1] + [0,1]×[0].
+    let square = ElementaryCube::from_intervals(&[(0, 1), (0, 1)]);
+    let boundary = square.boundary_with_orientations();
+    let sign_of = |intervals: &[(i64, i64)]| {
+      let face = ElementaryCube::from_intervals(intervals);
+      boundary.iter().find(|(f, _)| f.same_content(&face)).unwrap().1
+    };
+    assert_eq!(sign_of(&[(1, 1), (0, 1)]), 1);
+    assert_eq!(sign_of(&[(0, 0), (0, 1)]), -1);
+    assert_eq!(sign_of(&[(0, 1), (1, 1)]), -1);
+    assert_eq!(sign_of(&[(0, 1), (0, 0)]), 1);
+  }
+
+  #[test]
+  #[should_panic = "[0, 2] is not an elementary interval"]
+  fn test_non_elementary_interval() { ElementaryCube::from_intervals(&[(0, 2)]); }
+
+  #[test]
+  fn test_boundary_squared_is_zero() {
+    let mut complex = Complex::new();
+    let cube = complex.join_element(ElementaryCube::new(vec![0, 0, 0, 0], vec![true; 4]));
+    assert_eq!(complex.f_vector(), vec![16, 32, 24, 8, 1]);
+
+    let chain = Chain::from_item_and_coeff(&complex, cube, 1);
+    assert!(chain.boundary().boundary().items.is_empty());
+
+    let vertex_cube = ElementaryCube::from_intervals(&[(0, 1), (0, 1)])
+      .to_cube(|p| usize::try_from(p[0] + 2 * p[1]).unwrap());
+    assert_eq!(vertex_cube.vertices(), &[0, 1, 2, 3]);
+  }
+
+  #[test]
+  fn test_to_cube_preserves_orientations() {
+    let label = |p: &[i64]| usize::try_from(p[0] + 3 * p[1] + 9 * p[2] + 27 * p[3]).unwrap();
+    let cube = ElementaryCube::from_intervals(&[(0, 1), (1, 2), (1, 1), (0, 1)]);
+    let vertex_cube = cube.to_cube(label);
+
+    let elementary_boundary = cube.boundary_with_orientations();
+    let vertex_boundary = vertex_cube.boundary_with_orientations();
+    assert_eq!(elementary_boundary.len(), vertex_boundary.len());
+    for (face, incidence) in elementary_boundary {
+      let converted = face.to_cube(label);
+      let (_, vertex_incidence) =
+        vertex_boundary.iter().find(|(f, _)| f.same_content(&converted)).unwrap();
+      assert_eq!(incidence, *vertex_incidence, "face {face}");
+    }
+  }
+
+  #[test]
+  fn test_to_cube_matches_scalar_grid_with_reversed_axes() {
+    use crate::filtration::cubical::ScalarGrid;
+
+    // A 3×4 image; elementary cubes list the column axis first to match the grid's cubes.
+    let grid = ScalarGrid::new(vec![3, 4], vec![0.0; 12]);
+    let label =
+      |p: &[i64]| grid.label(&[usize::try_from(p[1]).unwrap(), usize::try_from(p[0]).unwrap()]);
+    let complex = grid.complex();
+    let square = ElementaryCube::from_intervals(&[(1, 2), (1, 2)]).to_cube(label);
+    assert_eq!(square.vertices(), &[5, 6, 9, 10]);
+    assert!(complex.elements_of_dimension(2).iter().any(|cube| cube.same_content(&square)));
+  }
+
+  #[test]
+  fn test_cubical_set_matches_complex() {
+    // A solid 5×5×5 block with an enclosed cavity and a tunnel drilled along the first axis.
+    let voxels: Vec<Vec<i64>> = (0..5)
+      .flat_map(|x| (0..5).flat_map(move |y| (0..5).map(move |z| vec![x, y, z])))
+      .filter(|v| v != &vec![1, 1, 1] && !(v[1] == 3 && v[2] == 3))
+      .collect();
+    let set = CubicalSet::from_voxels(voxels);
+    let complex = set.to_complex();
+    assert_eq!(set.f_vector(), complex.f_vector());
+    assert_eq!(set.euler_characteristic(), complex.euler_characteristic());
+
+    for k in 0..=3 {
+      let fast = set.homology::<Mod7>(k).betti_number;
+      assert_eq!(fast, complex.homology::<Mod7>(k).betti_number);
+      assert_eq!(set.homology::<Boolean>(k).betti_number, fast);
+    }
+    assert_eq!(set.homology::<Mod7>(0).betti_number, 1);
+    assert_eq!(set.homology::<Mod7>(1).betti_number, 1);
+    assert_eq!(set.homology::<Mod7>(2).betti_number, 1);
+  }
+
+  #[test]
+  fn test_cubical_set_insertion() {
+    let mut set = CubicalSet::new();
+    assert!(set.is_empty());
+    assert!(set.insert(ElementaryCube::from_intervals(&[(0, 1), (0, 0)])));
+    assert!(!set.insert(ElementaryCube::vertex(vec![1, 0])));
+    assert!(set.contains(&ElementaryCube::vertex(vec![0, 0])));
+    assert_eq!(set.f_vector(), vec![2, 1]);
+    assert_eq!(set.max_dimension(), 1);
+
+    let boundary = set.boundary_matrix::<Mod7>(1);
+    assert_eq!((boundary.nrows(), boundary.ncols(), boundary.nnz()), (2, 1, 2));
+    assert_eq!(set.boundary_matrix::<Mod7>(2).ncols(), 0);
+  }
+}
@@ -0,0 +1,345 @@
+//! # Filtered Complexes
+//!
+//! This module provides [`FilteredComplex`], a [`Complex`] in which every element carries a
+//! **filtration value** recording when it appears.
+//!
+//! ## Mathematical Background
+//!
+//! A filtration of a complex $K$ is a function $f: K \to \mathbb{R}$ that is monotone with respect
+//! to the face relation:
+//! $$ \tau \leq \sigma \implies f(\tau) \leq f(\sigma). $$
+//! Monotonicity guarantees that every sublevel set $K_t = \{ \sigma \in K : f(\sigma) \leq t \}$
+//! is itself a subcomplex, giving a nested family $K_s \subseteq K_t$ for $s \leq t$. This single
+//! object replaces a sequence of independently built complexes and is the input to persistent
+//! homology (see [`crate::persistence`]).
+//!
+//! ## Filtration Order
+//!
+//! Persistence algorithms process cells one at a time, so a filtration is refined to a total
+//! order: by filtration value, then by dimension (so a face always precedes its cofaces even
+//! when their values tie), then by the element's own [`Ord`] implementation.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use cova_space::complexes::{FilteredComplex, Simplex};
+//!
+//! let mut filtered = FilteredComplex::new();
+//! filtered.insert(Simplex::new(0, vec![0]), 0.0);
+//! filtered.insert(Simplex::new(0, vec![1]), 0.0);
+//! filtered.insert(Simplex::new(1, vec![0, 1]), 1.0);
+//!
+//! // Before time 1 only the two vertices are present.
+//! let early = filtered.truncate(0.5);
+//! assert_eq!(early.elements_of_dimension(0).len(), 2);
+//! assert!(early.elements_of_dimension(1).is_empty());
+//!
+//! // At time 1 the edge appears.
+//! let late = filtered.truncate(1.0);
+//! assert_eq!(late.elements_of_dimension(1).len(), 1);
+//! ```
+
+use std::{cmp::Ordering, collections::HashMap};
+
+use super::{Complex, ComplexElement};
+
+/// A complex together with a monotone filtration value for each of its elements.
+///
+/// The filtration values are keyed by element ID and are kept monotone at all times: inserting an
+/// element at some value also inserts any missing faces at that value and lowers the value of
+/// existing faces that would otherwise appear later than their new coface.
+///
+/// # Type Parameters
+///
+/// * `T`: The element type, must implement [`ComplexElement`]
+#[derive(Debug, Clone)]
+pub struct FilteredComplex<T: ComplexElement> {
+  /// The underlying complex containing every element of the filtration.
+  complex: Complex<T>,
+  /// The filtration value of each element, keyed by element ID.
+  values:  HashMap<usize, f64>,
+}
+
+impl<T: ComplexElement> FilteredComplex<T> {
+  /// Creates a new, empty filtered complex.
+  pub fn new() -> Self { Self { complex: Complex::new(), values: HashMap::new() } }
+
+  /// Creates a filtered complex from an existing complex and a filtration function.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `filtration` is not monotone, i.e. if some face of an element is assigned a
+  /// strictly larger value than the element itself.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use cova_space::{
+  ///   complexes::{FilteredComplex, Simplex, SimplicialComplex},
+  ///   prelude::*,
+  /// };
+  ///
+  /// let mut complex = SimplicialComplex::new();
+  /// complex.join_element(Simplex::new(2, vec![0, 1, 2]));
+  ///
+  /// // Filter by dimension: vertices at 0, edges at 1, the triangle at 2.
+  /// let filtered = FilteredComplex::from_complex(complex, |simplex| simplex.dimension() as f64);
+  /// assert_eq!(filtered.truncate(1.0).elements_of_dimension(2).len(), 0);
+  /// assert_eq!(filtered.truncate(2.0).elements_of_dimension(2).len(), 1);
+  /// ```
+  pub fn from_complex(complex: Complex<T>, filtration: impl Fn(&T) -> f64) -> Self {
+    let values = complex.elements.iter().map(|(id, element)| (*id, filtration(element))).collect();
+    let filtered = Self { complex, values };
+    assert!(
+      filtered.is_monotone(),
+      "filtration is not monotone: a face appears after one of its cofaces"
+    );
+    filtered
+  }
+
+  /// Adds an element to the filtration at the given value.
+  ///
+  /// Faces of `element` that are not yet present are added at the same value. If the element
+  /// or any of its faces is already present with a larger value, that value is lowered to
+  /// `value`, so each element ends up appearing at the earliest time it was inserted, either
+  /// directly or as a face.
+  ///
+  /// Returns the element as it exists in the underlying complex (with assigned ID).
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use cova_space::complexes::{FilteredComplex, Simplex};
+  ///
+  /// let mut filtered = FilteredComplex::new();
+  /// let edge = filtered.insert(Simplex::new(1, vec![0, 1]), 2.0);
+  /// // The vertices were added along with the edge...
+  /// let vertex = filtered.insert(Simplex::new(0, vec![0]), 0.5);
+  /// // ...and inserting one of them again moves it earlier.
+  /// assert_eq!(filtered.value(&vertex), Some(0.5));
+  /// assert_eq!(filtered.value(&edge), Some(2.0));
+  /// ```
+  pub fn insert(&mut self, element: T, value: f64) -> T {
+    let added = self.complex.join_element(element);
+
+    let mut stack = vec![added.clone()];
+    while let Some(current) = stack.pop() {
+      let id = current.id().expect("complex elements always carry an ID");
+      let entry = self.values.entry(id).or_insert(f64::INFINITY);
+      // Existing values are monotone, so faces of an element that is already early enough are
+      // early enough as well.
+      if *entry <= value {
+        continue;
+      }
+      *entry = value;
+      stack.extend(self.complex.faces(&current));
+    }
+
+    added
+  }
+
+  /// Returns the filtration value of an element, or `None` if it is not in the complex.
+  pub fn value(&self, element: &T) -> Option<f64> {
+    element.id().and_then(|id| self.values.get(&id).copied())
+  }
+
+  /// Returns a reference to the underlying (unfiltered) complex.
+  pub const fn complex(&self) -> &Complex<T> { &self.complex }
+
+  /// Consumes the filtered complex, returning the underlying complex.
+  pub fn into_complex(self) -> Complex<T> { self.complex }
+
+  /// Returns the number of elements in the filtration.
+  pub fn len(&self) -> usize { self.complex.elements.len() }
+
+  /// Returns `true` if the filtration contains no elements.
+  pub fn is_empty(&self) -> bool { self.complex.elements.is_empty() }
+
+  /// Checks that the filtration values are monotone with respect to the face relation.
+  ///
+  /// Returns `true` if every element has a filtration value that is at least as large as the
+  /// values of all of its faces.
+  pub fn is_monotone(&self) -> bool {
+    self.complex.elements.values().all(|element| {
+      let value = self.value(element).unwrap_or(f64::INFINITY);
+      self.complex.faces(element).iter().all(|face| self.value(face).is_some_and(|v| v <= value))
+    })
+  }
+
+  /// Returns all elements paired with their filtration values, in filtration order.
+  ///
+  /// Elements are sorted by filtration value, then by dimension, then by their [`Ord`]
+  /// implementation. Every face precedes all of its cofaces in this order.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use cova_space::{
+  ///   complexes::{FilteredComplex, Simplex},
+  ///   prelude::*,
+  /// };
+  ///
+  /// let mut filtered = FilteredComplex::new();
+  /// filtered.insert(Simplex::new(1, vec![0, 1]), 1.0);
+  ///
+  /// let order = filtered.filtration_order();
+  /// let dimensions: Vec<usize> = order.iter().map(|(simplex, _)| simplex.dimension()).collect();
+  /// assert_eq!(dimensions, vec![0, 0, 1]);
+  /// ```
+  pub fn filtration_order(&self) -> Vec<(T, f64)> {
+    let mut order: Vec<(T, f64)> = self
+      .complex
+      .elements
+      .iter()
+      .map(|(id, element)| (element.clone(), self.values[id]))
+      .collect();
+    order.sort_by(|(a, value_a), (b, value_b)| compare_filtration(a, *value_a, b, *value_b));
+    order
+  }
+
+  /// Returns the sublevel complex $K_t$ containing every element with value at most `value`.
+  ///
+  /// Elements keep the IDs they have in the filtered complex, so results from different
+  /// truncations can be compared by ID.
+  pub fn truncate(&self, value: f64) -> Complex<T> {
+    let mut complex = Complex::new();
+    for (element, _) in self.filtration_order().into_iter().take_while(|(_, v)| *v <= value) {
+      complex.join_element(element);
+    }
+    complex
+  }
+}
+
+impl<T: ComplexElement> Default for FilteredComplex<T> {
+  fn default() -> Self { Self::new() }
+}
+
+/// Compares two elements in filtration order: by value, then dimension, then element order.
+pub(crate) fn compare_filtration<T: ComplexElement>(
+  a: &T,
+  value_a: f64,
+  b: &T,
+  value_b: f64,
+) -> Ordering {
+  value_a.total_cmp(&value_b).then(a.dimension().cmp(&b.dimension())).then_with(|| a.cmp(b))
+}
+
+#[cfg(test)]
+mod tests {
+  #![allow(clippy::float_cmp)]
+
+  use super::*;
+  use crate::{
+    complexes::{Cube, Simplex, SimplicialComplex},
+    set::Collection,
+  };
+
+  fn hollow_triangle() -> FilteredComplex<Simplex> {
+    let mut filtered = FilteredComplex::new();
+    for v in 0..3 {
+      filtered.insert(Simplex::new(0, vec![v]), 0.0);
+    }
+    filtered.insert(Simplex::new(1, vec![0, 1]), 1.0);
+    filtered.insert(Simplex::new(1, vec![1, 2]), 2.0);
+    filtered.insert(Simplex::new(1, vec![0, 2]), 3.0);
+    filtered
+  }
+
+  #[test]
+  fn test_insert_adds_faces_at_same_value() {
+    let mut filtered = FilteredComplex::new();
+    let triangle = filtered.insert(Simplex::new(2, vec![0, 1, 2]), 4.0);
+
+    assert_eq!(filtered.len(), 7);
+    assert_eq!(filtered.value(&triangle), Some(4.0));
+    for element in filtered.complex().elements.values() {
+      assert_eq!(filtered.value(element), Some(4.0));
+    }
+    assert!(filtered.is_monotone());
+  }
+
+  #[test]
+  fn test_insert_lowers_faces() {
+    let mut filtered = FilteredComplex::new();
+    let triangle = filtered.insert(Simplex::new(2, vec![0, 1, 2]), 4.0);
+    let edge = filtered.insert(Simplex::new(1, vec![0, 1]), 1.0);
+    // Inserting later than the current value changes nothing.
+    filtered.insert(Simplex::new(1, vec![1, 2]), 9.0);
+
+    assert_eq!(filtered.value(&triangle), Some(4.0));
+    assert_eq!(filtered.value(&edge), Some(1.0));
+    for vertex in filtered.complex().elements_of_dimension(0) {
+      let expected = if vertex.vertices() == [2] { 4.0 } else { 1.0 };
+      assert_eq!(filtered.value(&vertex), Some(expected));
+    }
+    assert!(filtered.is_monotone());
+  }
+
+  #[test]
+  fn test_filtration_order_is_face_compatible() {
+    let mut filtered = FilteredComplex::new();
+    filtered.insert(Simplex::new(2, vec![0, 1, 2]), 1.0);
+    filtered.insert(Simplex::new(1, vec![2, 3]), 0.5);
+
+    let order = filtered.filtration_order();
+    assert_eq!(order.len(), filtered.len());
+    for (position, (element, value)) in order.iter().enumerate() {
+      for face in filtered.complex().faces(element) {
+        let face_position = order.iter().position(|(other, _)| other == &face).unwrap();
+        assert!(face_position < position);
+      }
+      if position > 0 {
+        assert!(order[position - 1].1 <= *value);
+      }
+    }
+  }
+
+  #[test]
+  fn test_truncate() {
+    let filtered = hollow_triangle();
+
+    let at_zero = filtered.truncate(0.0);
+    assert_eq!(at_zero.elements_of_dimension(0).len(), 3);
+    assert!(at_zero.elements_of_dimension(1).is_empty());
+
+    let at_two = filtered.truncate(2.5);
+    assert_eq!(at_two.elements_of_dimension(1).len(), 2);
+
+    let full = filtered.truncate(f64::INFINITY);
+    assert_eq!(full.elements.len(), filtered.len());
+    // IDs are preserved by truncation.
+    for element in full.elements.values() {
+      assert!(filtered.complex().contains(element));
+      assert_eq!(filtered.complex().get_element(element.id().unwrap()), Some(element));
+    }
+  }
+
+  #[test]
+  fn test_from_complex() {
+    let mut complex = SimplicialComplex::new();
+    complex.join_element(Simplex::new(2, vec![0, 1, 2]));
+
+    let filtered = FilteredComplex::from_complex(complex, |s| s.dimension() as f64);
+    assert!(filtered.is_monotone());
+    assert_eq!(filtered.truncate(1.0).elements.len(), 6);
+  }
+
+  #[test]
+  #[should_panic(expected = "filtration is not monotone")]
+  fn test_from_complex_rejects_non_monotone() {
+    let mut complex = SimplicialComplex::new();
+    complex.join_element(Simplex::new(1, vec![0, 1]));
+
+    FilteredComplex::from_complex(complex, |s| if s.dimension() == 0 { 2.0 } else { 1.0 });
+  }
+
+  #[test]
+  fn test_cubical_filtration() {
+    let mut filtered = FilteredComplex::new();
+    filtered.insert(Cube::square([0, 1, 2, 3]), 2.0);
+    filtered.insert(Cube::edge(0, 1), 1.0);
+
+    assert_eq!(filtered.truncate(1.0).elements.len(), 3);
+    assert_eq!(filtered.truncate(2.0).elements.len(), 9);
+  }
+}
@@ -0,0 +1,443 @@
+//! # Simplicial Maps
+//!
+//! This module provides [`SimplicialMap`], a structure-preserving map between two
+//! [`SimplicialComplex`]es, together with the maps it induces on chains and on homology.
+//!
+//! ## Mathematical Background
+//!
+//! A **simplicial map** $f: K \to L$ is a map on vertices such that for every simplex
+//! $\sigma = [v_0, \ldots, v_k]$ of $K$ the image vertices $\{f(v_0), \ldots, f(v_k)\}$ span a
+//! simplex of $L$. The image may have lower dimension when vertices are identified.
+//!
+//! Every simplicial map induces a **chain map** $f_\#: C_k(K) \to C_k(L)$ given on oriented
+//! simplices by
+//!
+//! $$
+//! f_\#[v_0, \ldots, v_k] =
+//! \begin{cases}
+//!   [f(v_0), \ldots, f(v_k)] & \text{if the } f(v_i) \text{ are distinct}, \\
+//!   0 & \text{otherwise}.
+//! \end{cases}
+//! $$
+//!
+//! The chain map commutes with the boundary, $\partial f_\# = f_\# \partial$, so it sends cycles
+//! to cycles and boundaries to boundaries and therefore descends to a linear map
+//! $f_*: H_k(K) \to H_k(L)$ on homology. This is functorial: $(g \circ f)_* = g_* \circ f_*$,
+//! which is what allows features to be tracked along a pipeline of maps such as inclusions,
+//! subsamplings or simplicial approximations of refinements.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use std::collections::HashMap;
+//!
+//! use cova_algebra::algebras::boolean::Boolean;
+//! use cova_space::complexes::{Simplex, SimplicialComplex, SimplicialMap};
+//!
+//! // A hexagon and a triangle, both circles.
+//! let mut hexagon = SimplicialComplex::new();
+//! for i in 0..6 {
+//!   hexagon.join_element(Simplex::new(1, vec![i, (i + 1) % 6]));
+//! }
+//! let mut triangle = SimplicialComplex::new();
+//! for i in 0..3 {
+//!   triangle.join_element(Simplex::new(1, vec![i, (i + 1) % 3]));
+//! }
+//!
+//! // Wrapping the hexagon once around the triangle.
+//! let vertex_map: HashMap<usize, usize> = (0..6).map(|i| (i, (i + 1) / 2 % 3)).collect();
+//! let f = SimplicialMap::new(&hexagon, &triangle, vertex_map);
+//!
+//! // The loop of the hexagon is sent to the loop of the triangle.
+//! let h1 = f.induced_homology_map::<Boolean>(1);
+//! assert_eq!(h1.matrix.shape(), (1, 1));
+//! assert_eq!(h1.matrix[(0, 0)], Boolean(true));
+//! ```
+
+use std::collections::HashMap;
+
+use cova_algebra::{
+  rings::Field,
+  tensors::{DMatrix, rref_with_pivots},
+};
+
+use super::*;
+use crate::homology::{Chain, Homology};
+
+/// A simplicial map between two simplicial complexes, given by a map on vertex labels.
+///
+/// The map borrows its domain and codomain, in the same way a [`Chain`] borrows the space it
+/// lives in; chains produced by [`SimplicialMap::map_chain`] live in the codomain.
+#[derive(Debug, Clone)]
+pub struct SimplicialMap<'a> {
+  /// The complex the map is defined on.
+  domain:     &'a SimplicialComplex,
+  /// The complex the map takes values in.
+  codomain:   &'a SimplicialComplex,
+  /// The image of each vertex label of the domain.
+  vertex_map: HashMap<usize, usize>,
+}
+
+/// The linear map $f_*: H_k(K) \to H_k(L)$ induced by a [`SimplicialMap`] on homology.
+///
+/// The matrix is expressed in the bases of homology generators stored alongside it: column `j`
+/// holds the coordinates of $f_*$ applied to `domain.homology_generators[j]`, written in terms of
+/// `codomain.homology_generators` modulo boundaries.
+#[derive(Debug, Clone)]
+pub struct InducedHomologyMap<F: Field + Copy> {
+  /// The homology group $H_k(K)$ of the domain, whose generators index the columns.
+  pub domain:   Homology<F>,
+  /// The homology group $H_k(L)$ of the codomain, whose generators index the rows.
+  pub codomain: Homology<F>,
+  /// The matrix of $f_*$, of shape $\beta_k(L) \times \beta_k(K)$.
+  pub matrix:   DMatrix<F>,
+}
+
+impl<F: Field + Copy> InducedHomologyMap<F> {
+  /// Returns the rank of the induced map, i.e. the number of independent homology classes of the
+  /// domain that survive in the codomain.
+  pub fn rank(&self) -> usize { rref_with_pivots(&self.matrix).1.len() }
+}
+
+impl<'a> SimplicialMap<'a> {
+  /// Creates a simplicial map from a map on vertex labels.
+  ///
+  /// # Panics
+  ///
+  /// Panics if a vertex of `domain` has no image in `vertex_map`, if an image is not a vertex of
+  /// `codomain`, or if the image of some simplex of `domain` is not a simplex of `codomain`.
+  pub fn new(
+    domain: &'a SimplicialComplex,
+    codomain: &'a SimplicialComplex,
+    vertex_map: HashMap<usize, usize>,
+  ) -> Self {
+    for vertex in domain.elements_of_dimension(0) {
+      let image = vertex_map
+        .get(&vertex.vertices()[0])
+        .unwrap_or_else(|| panic!("vertex {} of the domain has no image", vertex.vertices()[0]));
+      assert!(
+        codomain.find_equivalent_element(&Simplex::new(0, vec![*image])).is_some(),
+        "vertex {image} is not a vertex of the codomain"
+      );
+    }
+
+    let map = Self { domain, codomain, vertex_map };
+    for simplex in domain.elements.values() {
+      assert!(
+        codomain.find_equivalent_element(&map.image_simplex(simplex)).is_some(),
+        "the image of simplex {:?} is not a simplex of the codomain",
+        simplex.vertices()
+      );
+    }
+    map
+  }
+
+  /// Creates the inclusion of `domain` into `codomain`, sending every vertex label to itself.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `domain` is not a subcomplex of `codomain`.
+  pub fn inclusion(domain: &'a SimplicialComplex, codomain: &'a SimplicialComplex) -> Self {
+    let vertex_map = domain
+      .elements_of_dimension(0)
+      .into_iter()
+      .map(|vertex| (vertex.vertices()[0], vertex.vertices()[0]))
+      .collect();
+    Self::new(domain, codomain, vertex_map)
+  }
+
+  /// Returns the domain of the map.
+  pub const fn domain(&self) -> &'a SimplicialComplex { self.domain }
+
+  /// Returns the codomain of the map.
+  pub const fn codomain(&self) -> &'a SimplicialComplex { self.codomain }
+
+  /// Returns the image of a vertex label of the domain, if it is mapped.
+  pub fn vertex_image(&self, vertex: usize) -> Option<usize> {
+    self.vertex_map.get(&vertex).copied()
+  }
+
+  /// Returns the simplex of the codomain spanned by the image of `simplex`.
+  ///
+  /// The result has lower dimension than `simplex` whenever the map identifies some of its
+  /// vertices.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `simplex` is not a simplex of the domain.
+  pub fn image(&self, simplex: &Simplex) -> Simplex {
+    assert!(
+      self.domain.find_equivalent_element(simplex).is_some(),
+      "simplex {:?} is not in the domain",
+      simplex.vertices()
+    );
+    self
+      .codomain
+      .find_equivalent_element(&self.image_simplex(simplex))
+      .expect("images of simplices are validated on construction")
+  }
+
+  /// Composes this map with `next`, giving the map `next ∘ self`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the codomain of `self` is not the domain of `next` (compared by reference).
+  pub fn compose(&self, next: &SimplicialMap<'a>) -> SimplicialMap<'a> {
+    assert!(
+      std::ptr::eq(self.codomain, next.domain),
+      "the codomain of the first map must be the domain of the second"
+    );
+    let vertex_map =
+      self.vertex_map.iter().map(|(&vertex, image)| (vertex, next.vertex_map[image])).collect();
+    SimplicialMap { domain: self.domain, codomain: next.codomain, vertex_map }
+  }
+
+  /// Applies the induced chain map $f_\#$ to a chain of the domain.
+  ///
+  /// Simplices whose image is degenerate are sent to zero, and the others are sent to their image
+  /// with the sign of the permutation that sorts the image vertices. Like terms are combined and
+  /// the result lists its simplices in the order of [`Complex::basis`].
+  pub fn map_chain<R: Ring + Copy>(
+    &self,
+    chain: &Chain<'_, SimplicialComplex, R>,
+  ) -> Chain<'a, SimplicialComplex, R> {
+    let mut coefficients: HashMap<Simplex, R> = HashMap::new();
+    for (simplex, coeff) in chain.items.iter().zip(chain.coefficients.iter()) {
+      if let Some((image, positive)) = self.oriented_image(simplex) {
+        let term = if positive { *coeff } else { -*coeff };
+        let entry = coefficients.entry(image).or_insert_with(R::zero);
+        *entry += term;
+      }
+    }
+
+    let mut terms: Vec<(Simplex, R)> =
+      coefficients.into_iter().filter(|(_, coeff)| !coeff.is_zero()).collect();
+    terms.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+    let (items, coeffs) = terms.into_iter().unzip();
+    Chain::from_items_and_coeffs(self.codomain, items, coeffs)
+  }
+
+  /// Returns the matrix of the chain map $f_\#: C_k(K) \to C_k(L)$.
+  ///
+  /// Columns are indexed by `domain.basis(k)` and rows by `codomain.basis(k)` (see
+  /// [`Complex::basis`]); each column has at most one non-zero entry, which is ±1.
+  pub fn chain_matrix<F: Field + Copy>(&self, k: usize) -> DMatrix<F> {
+    let domain_basis = self.domain.basis(k);
+    let codomain_basis = self.codomain.basis(k);
+    let row_of: HashMap<&Simplex, usize> =
+      codomain_basis.iter().enumerate().map(|(row, simplex)| (simplex, row)).collect();
+
+    let mut matrix = DMatrix::from_element(codomain_basis.len(), domain_basis.len(), F::zero());
+    for (column, simplex) in domain_basis.iter().enumerate() {
+      if let Some((image, positive)) = self.oriented_image(simplex) {
+        matrix[(row_of[&image], column)] = if positive { F::one() } else { -F::one() };
+      }
+    }
+    matrix
+  }
+
+  /// Computes the linear map $f_*: H_k(K; F) \to H_k(L; F)$ induced on homology.
+  ///
+  /// Homology of both complexes is computed with [`Complex::homology`], and the returned
+  /// [`InducedHomologyMap`] records the generators its matrix is expressed in. Each domain
+  /// generator $z$ is pushed forward to the cycle $f_\# z$, which is then written as a combination
+  /// of codomain generators plus a boundary; the coefficients of the generators form one column.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use std::collections::HashMap;
+  ///
+  /// use cova_algebra::algebras::boolean::Boolean;
+  /// use cova_space::complexes::{Simplex, SimplicialComplex, SimplicialMap};
+  ///
+  /// // Filling in a circle kills its loop.
+  /// let mut circle = SimplicialComplex::new();
+  /// for edge in [[0, 1], [1, 2], [0, 2]] {
+  ///   circle.join_element(Simplex::new(1, edge.to_vec()));
+  /// }
+  /// let mut disk = SimplicialComplex::new();
+  /// disk.join_element(Simplex::new(2, vec![0, 1, 2]));
+  ///
+  /// let inclusion = SimplicialMap::inclusion(&circle, &disk);
+  /// let h1 = inclusion.induced_homology_map::<Boolean>(1);
+  /// assert_eq!(h1.domain.betti_number, 1);
+  /// assert_eq!(h1.codomain.betti_number, 0);
+  /// assert_eq!(h1.rank(), 0);
+  ///
+  /// // Connected components are preserved.
+  /// assert_eq!(inclusion.induced_homology_map::<Boolean>(0).rank(), 1);
+  /// ```
+  pub fn induced_homology_map<F: Field + Copy>(&self, k: usize) -> InducedHomologyMap<F> {
+    let domain = self.domain.homology::<F>(k);
+    let codomain = self.codomain.homology::<F>(k);
+    let mut matrix = DMatrix::from_element(codomain.betti_number, domain.betti_number, F::zero());
+    if domain.betti_number == 0 || codomain.betti_number == 0 {
+      return InducedHomologyMap { domain, codomain, matrix };
+    }
+
+    // Solve [generators | ∂ₖ₊₁ | f#z₁ ... f#zₘ]: the generators are independent modulo
+    // boundaries, so they are the first pivots and every pushed-forward cycle reduces to a
+    // combination of them (rows 0..β) plus boundaries.
+    let chain_matrix = self.chain_matrix::<F>(k);
+    let boundaries = self.codomain.get_boundary_matrix::<F>(k + 1);
+    let images: Vec<_> =
+      domain.homology_generators.iter().map(|generator| &chain_matrix * generator).collect();
+    let columns: Vec<_> = codomain
+      .homology_generators
+      .iter()
+      .cloned()
+      .chain(boundaries.column_iter().map(|column| column.into_owned()))
+      .chain(images)
+      .collect();
+    let (reduced, _) = rref_with_pivots(&DMatrix::from_columns(&columns));
+
+    let offset = columns.len() - domain.betti_number;
+    for j in 0..domain.betti_number {
+      for i in 0..codomain.betti_number {
+        matrix[(i, j)] = reduced[(i, offset + j)];
+      }
+    }
+    InducedHomologyMap { domain, codomain, matrix }
+  }
+
+  /// Returns the simplex spanned by the image vertices of `simplex`, which may be degenerate.
+  fn image_simplex(&self, simplex: &Simplex) -> Simplex {
+    let mut vertices: Vec<usize> =
+      simplex.vertices().iter().map(|vertex| self.vertex_map[vertex]).collect();
+    vertices.sort_unstable();
+    vertices.dedup();
+    Simplex::from_vertices(vertices)
+  }
+
+  /// Returns the codomain simplex $f_\#\sigma$ and whether it carries a positive sign, or `None`
+  /// if the image of `simplex` is degenerate.
+  fn oriented_image(&self, simplex: &Simplex) -> Option<(Simplex, bool)> {
+    let images: Vec<usize> =
+      simplex.vertices().iter().map(|vertex| self.vertex_map[vertex]).collect();
+    let mut inversions = 0;
+    for i in 0..images.len() {
+      for j in i + 1..images.len() {
+        match images[i].cmp(&images[j]) {
+          std::cmp::Ordering::Equal => return None,
+          std::cmp::Ordering::Greater => inversions += 1,
+          std::cmp::Ordering::Less => {},
+        }
+      }
+    }
+    let image = self.codomain.find_equivalent_element(&Simplex::from_vertices(images))?;
+    Some((image, inversions % 2 == 0))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use cova_algebra::{algebras::boolean::Boolean, modular, prime_field};
+
+  use super::*;
+
+  modular!(Mod7, u32, 7);
+  prime_field!(Mod7);
+
+  fn cycle(n: usize) -> SimplicialComplex {
+    let mut complex = SimplicialComplex::new();
+    for i in 0..n {
+      complex.join_element(Simplex::from_vertices(vec![i, (i + 1) % n]));
+    }
+    complex
+  }
+
+  #[test]
+  fn test_chain_map_commutes_with_boundary() {
+    // Fold a filled square onto a triangle by identifying two opposite corners.
+    let mut square = SimplicialComplex::new();
+    square.join_element(Simplex::new(2, vec![0, 1, 2]));
+    square.join_element(Simplex::new(2, vec![0, 2, 3]));
+    let mut triangle = SimplicialComplex::new();
+    triangle.join_element(Simplex::new(2, vec![0, 1, 2]));
+
+    let f = SimplicialMap::new(&square, &triangle, HashMap::from([(0, 0), (1, 1), (2, 2), (3, 1)]));
+    for k in 1..=2 {
+      let d_domain = square.get_boundary_matrix::<Mod7>(k);
+      let d_codomain = triangle.get_boundary_matrix::<Mod7>(k);
+      assert_eq!(
+        &d_codomain * f.chain_matrix::<Mod7>(k),
+        f.chain_matrix::<Mod7>(k - 1) * &d_domain,
+        "dimension {k}"
+      );
+    }
+
+    // Both triangles land on the same triangle with opposite orientations and cancel.
+    let chain = Chain::from_items_and_coeffs(&square, square.elements_of_dimension(2), vec![
+      Mod7::new(1),
+      Mod7::new(1),
+    ]);
+    assert!(f.map_chain(&chain).items.is_empty());
+    assert_eq!(f.image(&Simplex::new(1, vec![2, 3])).vertices(), &[1, 2]);
+  }
+
+  #[test]
+  fn test_induced_homology_map_degree() {
+    // The map z ↦ z² on the circle wraps a hexagon twice around a triangle. Over ℤ/7 this has
+    // degree 2, while over ℤ/2 it is zero.
+    let hexagon = cycle(6);
+    let triangle = cycle(3);
+    let f = SimplicialMap::new(&hexagon, &triangle, (0..6).map(|i| (i, i % 3)).collect());
+
+    let over_mod7 = f.induced_homology_map::<Mod7>(1);
+    assert_eq!(over_mod7.matrix.shape(), (1, 1));
+    let degree = over_mod7.matrix[(0, 0)];
+    assert!(degree == Mod7::new(2) || degree == Mod7::new(5));
+    assert_eq!(f.induced_homology_map::<Boolean>(1).rank(), 0);
+    assert_eq!(f.induced_homology_map::<Boolean>(0).rank(), 1);
+  }
+
+  #[test]
+  fn test_induced_homology_map_is_functorial() {
+    let hexagon = cycle(6);
+    let square = cycle(4);
+    let triangle = cycle(3);
+    let f =
+      SimplicialMap::new(&hexagon, &square, (0..6).map(|i| (i, [0, 1, 2, 3, 3, 0][i])).collect());
+    let g = SimplicialMap::new(&square, &triangle, (0..4).map(|i| (i, [0, 1, 2, 2][i])).collect());
+    let gf = f.compose(&g);
+
+    let (f1, g1, gf1) = (
+      f.induced_homology_map::<Mod7>(1),
+      g.induced_homology_map::<Mod7>(1),
+      gf.induced_homology_map::<Mod7>(1),
+    );
+    assert_eq!(&g1.matrix * &f1.matrix, gf1.matrix);
+    assert_eq!(gf1.rank(), 1);
+  }
+
+  #[test]
+  fn test_inclusion_of_subcomplex() {
+    // Two loops sharing a vertex, and one of them on its own.
+    let mut figure_eight = cycle(3);
+    for edge in [[0, 3], [3, 4], [0, 4]] {
+      figure_eight.join_element(Simplex::new(1, edge.to_vec()));
+    }
+    let circle = cycle(3);
+
+    let inclusion = SimplicialMap::inclusion(&circle, &figure_eight);
+    assert_eq!(inclusion.vertex_image(2), Some(2));
+    let h1 = inclusion.induced_homology_map::<Boolean>(1);
+    assert_eq!(h1.matrix.shape(), (2, 1));
+    assert_eq!(h1.rank(), 1);
+  }
+
+  #[test]
+  #[should_panic(expected = "is not a simplex of the codomain")]
+  fn test_rejects_non_simplicial_vertex_map() {
+    let edge = {
+      let mut complex = SimplicialComplex::new();
+      complex.join_element(Simplex::new(1, vec![0, 1]));
+      complex
+    };
+    let mut points = SimplicialComplex::new();
+    points.join_element(Simplex::new(0, vec![0]));
+    points.join_element(Simplex::new(0, vec![1]));
+    SimplicialMap::new(&edge, &points, HashMap::from([(0, 0), (1, 1)]));
+  }
+}
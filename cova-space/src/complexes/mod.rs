@@ -128,6 +128,12 @@
 //!
 //! - [`simplicial`]: Definitions for [`Simplex`] and simplicial complex operations
 //! - [`cubical`]: Definitions for [`Cube`] and cubical complex operations
+//! - [`collapse`]: Elementary collapses along free faces, shrinking a complex before homology
+//! - [`constructions`]: Cones, suspensions, joins, wedge sums, disjoint unions and products
+//! - [`filtered`]: [`FilteredComplex`], a complex with a monotone filtration value per element
+//! - [`maps`]: [`SimplicialMap`], maps between simplicial complexes and the maps they induce on
+//!   chains and homology
+//! - [`subdivision`]: Barycentric and cubical subdivision with carrier maps
 //!
 //! ## Examples
 //!
@@ -137,26 +143,42 @@
 //! - Working with the poset and topology interfaces
 //! - Verifying fundamental properties like ∂² = 0
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use cova_algebra::{
   rings::Field,
-  tensors::{DMatrix, DVector, compute_quotient_basis, image, kernel},
+  tensors::{
+    DMatrix,
+    smith::invariant_factors,
+    sparse::{SparseMatrix, kernel_modulo_image},
+  },
 };
 
 use super::*;
 use crate::{
   definitions::Topology,
-  homology::{Chain, Homology},
+  homology::{Chain, Cohomology, Homology, IntegralHomology},
   lattice::Lattice,
   set::{Collection, Poset},
 };
 
+pub mod cell;
+pub mod collapse;
+pub mod constructions;
 pub mod cubical;
+pub mod elementary;
+pub mod filtered;
+pub mod maps;
 pub mod simplicial;
+pub mod subdivision;
 
+pub use cell::{Cell, CellComplexBuilder};
 pub use cubical::Cube;
+pub use elementary::{CubicalSet, ElementaryCube};
+pub use filtered::FilteredComplex;
+pub use maps::SimplicialMap;
 pub use simplicial::Simplex;
+pub use subdivision::Subdivision;
 
 /// A type alias for a simplicial complex.
 pub type SimplicialComplex = Complex<Simplex>;
@@ -164,6 +186,9 @@ pub type SimplicialComplex = Complex<Simplex>;
 /// A type alias for a cubical complex.
 pub type CubicalComplex = Complex<Cube>;
 
+/// A type alias for a CW complex.
+pub type CellComplex = Complex<Cell>;
+
 /// Trait for elements that can be part of a topological complex.
 ///
 /// This trait captures the essential behavior needed for elements (simplices, cubes, cells, etc.)
@@ -221,6 +246,12 @@ pub type CubicalComplex = Complex<Cube>;
 /// assert!(boundary.iter().all(|(_, orient)| orient.abs() == 1));
 /// ```
 pub trait ComplexElement: Clone + std::hash::Hash + Eq + PartialOrd + Ord {
+  /// A hashable key describing the mathematical content of an element, independent of its ID.
+  ///
+  /// Two elements have the same content exactly when their keys are equal. [`Complex`] indexes
+  /// its elements by this key, which makes deduplication and face lookups constant time.
+  type Content: Clone + std::hash::Hash + Eq + std::fmt::Debug;
+
   /// Returns the intrinsic dimension of this element.
   ///
   /// The dimension determines the element's place in the chain complex:
@@ -356,6 +387,21 @@ pub trait ComplexElement: Clone + std::hash::Hash + Eq + PartialOrd + Ord {
   /// ```
   fn id(&self) -> Option<usize>;
 
+  /// Returns the content key of this element.
+  ///
+  /// The key must ignore the ID and agree with [`ComplexElement::same_content`]: two elements
+  /// have equal keys if and only if they have the same content.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use cova_space::complexes::{ComplexElement, Simplex};
+  /// let edge = Simplex::new(1, vec![0, 1]);
+  /// assert_eq!(edge.content(), edge.clone().with_id(7).content());
+  /// assert_ne!(edge.content(), Simplex::new(1, vec![0, 2]).content());
+  /// ```
+  fn content(&self) -> Self::Content;
+
   /// Checks if this element has the same mathematical content as another.
   ///
   /// This comparison ignores ID assignment and focuses purely on the mathematical
@@ -390,7 +436,7 @@ pub trait ComplexElement: Clone + std::hash::Hash + Eq + PartialOrd + Ord {
   /// let different = Simplex::new(1, vec![0, 2]);
   /// assert!(!simplex1.same_content(&different)); // Different content
   /// ```
-  fn same_content(&self, other: &Self) -> bool;
+  fn same_content(&self, other: &Self) -> bool { self.content() == other.content() }
 
   /// Creates a new element with the same content but a specific ID.
   ///
@@ -460,6 +506,12 @@ pub trait ComplexElement: Clone + std::hash::Hash + Eq + PartialOrd + Ord {
 /// - **Elements HashMap**: Stores actual elements indexed by unique IDs
 /// - **Attachment Lattice**: Tracks face relationships using IDs for efficiency
 /// - **ID Management**: Automatic assignment with deduplication support
+/// - **Content Index**: Maps each element's [`ComplexElement::content`] key to its ID
+/// - **Dimension Index**: Lists the IDs of the elements of each dimension
+///
+/// The indices are maintained by [`Complex::join_element`]; the public `elements` and
+/// `attachment_lattice` fields should be treated as read-only, since modifying them directly
+/// bypasses the indices.
 ///
 /// # Key Properties
 ///
@@ -479,7 +531,7 @@ pub trait ComplexElement: Clone + std::hash::Hash + Eq + PartialOrd + Ord {
 /// ## Deduplication
 ///
 /// Elements with identical mathematical content are automatically deduplicated
-/// using [`ComplexElement::same_content`], preventing redundant storage and
+/// using their [`ComplexElement::content`] key, preventing redundant storage and
 /// maintaining well-defined structure.
 ///
 /// # Usage Patterns
@@ -542,9 +594,10 @@ pub trait ComplexElement: Clone + std::hash::Hash + Eq + PartialOrd + Ord {
 ///
 /// # Performance Characteristics
 ///
-/// - **Element Access**: O(1) by ID, O(n) by content search
-/// - **Face Queries**: O(1) for direct faces, O(k) for k-dimensional queries
-/// - **Adding Elements**: O(f) where f is the number of faces to add
+/// - **Element Access**: O(1) by ID, expected O(1) by content
+/// - **Face Queries**: O(f) for the f direct faces, O(k) for the k elements of a dimension
+/// - **Adding Elements**: O(f) lookups where f is the number of faces to add, plus the incremental
+///   update of the lattice's transitive closure
 /// - **Homology**: O(n³) where n is the number of elements in relevant dimensions
 ///
 /// # Type Parameters
@@ -585,6 +638,16 @@ pub struct Complex<T: ComplexElement> {
   /// - IDs are assigned sequentially for predictable behavior
   /// - The complex can manage arbitrary numbers of elements
   pub next_id: usize,
+
+  /// Maps the content key of every element to its ID, used for constant-time deduplication and
+  /// face lookups.
+  content_index: HashMap<T::Content, usize>,
+
+  /// The IDs of the elements of each dimension, in insertion order.
+  dimension_index: Vec<Vec<usize>>,
+
+  /// Maps the ID of every element to the IDs of its direct cofaces, in insertion order.
+  coface_index: HashMap<usize, Vec<usize>>,
 }
 
 impl<T: ComplexElement> Complex<T> {
@@ -611,6 +674,9 @@ impl<T: ComplexElement> Complex<T> {
       attachment_lattice: Lattice::new(),
       elements:           HashMap::new(),
       next_id:            0,
+      content_index:      HashMap::new(),
+      dimension_index:    Vec::new(),
+      coface_index:       HashMap::new(),
     }
   }
 
@@ -654,7 +720,8 @@ impl<T: ComplexElement> Complex<T> {
   ///
   /// # Deduplication Logic
   ///
-  /// Uses [`ComplexElement::same_content`] to check for existing equivalent elements.
+  /// Looks up the element's [`ComplexElement::content`] key to check for existing equivalent
+  /// elements.
   /// This ensures that mathematically identical elements (regardless of ID) are not
   /// duplicated in the complex.
   ///
@@ -674,7 +741,7 @@ impl<T: ComplexElement> Complex<T> {
   ///
   /// - **Time**: O(f) where f is the total number of faces to add (including recursive)
   /// - **Space**: O(n) additional storage where n is the number of new elements
-  /// - **Deduplication**: O(k) check where k is the number of existing elements
+  /// - **Deduplication**: Expected O(1) hash lookup of the element's content key
   ///
   /// # Examples
   ///
@@ -755,14 +822,23 @@ impl<T: ComplexElement> Complex<T> {
       let added_face = self.join_element(face);
       face_ids.push(added_face.id().unwrap()); // Safe because we just added it
     }
+    face_ids.sort_unstable();
+    face_ids.dedup();
 
     let element_id = element_with_id.id().unwrap();
     self.attachment_lattice.add_element(element_id);
 
     for face_id in face_ids {
       self.attachment_lattice.add_relation(face_id, element_id);
+      self.coface_index.entry(face_id).or_default().push(element_id);
     }
 
+    let dimension = element_with_id.dimension();
+    if self.dimension_index.len() <= dimension {
+      self.dimension_index.resize_with(dimension + 1, Vec::new);
+    }
+    self.dimension_index[dimension].push(element_id);
+    self.content_index.insert(element_with_id.content(), element_id);
     self.elements.insert(element_id, element_with_id.clone());
     element_with_id
   }
@@ -774,18 +850,10 @@ impl<T: ComplexElement> Complex<T> {
   ///
   /// # Performance
   ///
-  /// This performs a linear search through all elements, so it's O(n) where n is the
-  /// total number of elements in the complex. For large complexes, this can be a
-  /// bottleneck in construction.
-  ///
-  /// # Future Optimizations
-  ///
-  /// Potential improvements could include:
-  /// - Content-based hashing for faster lookup
-  /// - Spatial indexing for geometric elements
-  /// - Dimension-stratified search to reduce search space
-  fn find_equivalent_element(&self, element: &T) -> Option<T> {
-    self.elements.values().find(|existing| element.same_content(existing)).cloned()
+  /// Expected O(1): the element's [`ComplexElement::content`] key is looked up in a hash index
+  /// maintained by [`join_element`].
+  pub(crate) fn find_equivalent_element(&self, element: &T) -> Option<T> {
+    self.content_index.get(&element.content()).and_then(|id| self.elements.get(id)).cloned()
   }
 
   /// Retrieves an element by its unique ID.
@@ -840,7 +908,33 @@ impl<T: ComplexElement> Complex<T> {
   /// assert_eq!(faces.len(), 1);
   /// ```
   pub fn elements_of_dimension(&self, dimension: usize) -> Vec<T> {
-    self.elements.values().filter(|element| element.dimension() == dimension).cloned().collect()
+    self.dimension_index.get(dimension).map_or_else(Vec::new, |ids| {
+      ids.iter().filter_map(|id| self.elements.get(id)).cloned().collect()
+    })
+  }
+
+  /// Returns the k-elements of the complex in the order used as a basis for k-chains.
+  ///
+  /// Elements are sorted by their [`Ord`] implementation. This is the ordering of the columns of
+  /// ∂ₖ and the rows of ∂ₖ₊₁ (see [`Complex::get_boundary_matrix`]), and therefore the basis in
+  /// which [`Homology::homology_generators`] and [`Cohomology::cocycle_representatives`] are
+  /// expressed.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use cova_space::complexes::{Complex, ComplexElement, Simplex};
+  /// # let mut complex = Complex::new();
+  /// complex.join_element(Simplex::new(1, vec![1, 2]));
+  /// complex.join_element(Simplex::new(1, vec![0, 1]));
+  ///
+  /// let edges: Vec<_> = complex.basis(1).iter().map(|edge| edge.vertices().to_vec()).collect();
+  /// assert_eq!(edges, vec![vec![0, 1], vec![1, 2]]);
+  /// ```
+  pub fn basis(&self, k: usize) -> Vec<T> {
+    let mut basis = self.elements_of_dimension(k);
+    basis.sort_unstable();
+    basis
   }
 
   /// Returns the maximum dimension of any element in the complex.
@@ -859,8 +953,67 @@ impl<T: ComplexElement> Complex<T> {
   /// complex.join_element(triangle);
   /// assert_eq!(complex.max_dimension(), 2); // 2D complex
   /// ```
-  pub fn max_dimension(&self) -> usize {
-    self.elements.values().map(ComplexElement::dimension).max().unwrap_or(0)
+  pub fn max_dimension(&self) -> usize { self.dimension_index.len().saturating_sub(1) }
+
+  /// Returns the f-vector of the complex: the number of elements in each dimension.
+  ///
+  /// Entry `i` is fᵢ, the number of i-dimensional elements, for i = 0, ..., `max_dimension()`.
+  /// An empty complex has an empty f-vector.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use cova_space::complexes::{Complex, Simplex};
+  /// # let mut complex = Complex::new();
+  /// assert!(complex.f_vector().is_empty());
+  ///
+  /// complex.join_element(Simplex::new(2, vec![0, 1, 2]));
+  /// assert_eq!(complex.f_vector(), vec![3, 3, 1]);
+  /// ```
+  pub fn f_vector(&self) -> Vec<usize> { self.dimension_index.iter().map(Vec::len).collect() }
+
+  /// Returns the Euler characteristic χ = Σₖ (-1)ᵏ fₖ of the complex.
+  ///
+  /// By the Euler–Poincaré formula this also equals Σₖ (-1)ᵏ βₖ for the Betti numbers over any
+  /// field, which makes it a cheap consistency check for [`Complex::homology`].
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use cova_space::complexes::{Complex, Simplex};
+  /// # let mut complex = Complex::new();
+  /// // Tetrahedron boundary: χ(S²) = 4 - 6 + 4 = 2
+  /// for face in [[0, 1, 2], [0, 1, 3], [0, 2, 3], [1, 2, 3]] {
+  ///   complex.join_element(Simplex::new(2, face.to_vec()));
+  /// }
+  /// assert_eq!(complex.euler_characteristic(), 2);
+  /// ```
+  pub fn euler_characteristic(&self) -> isize {
+    self
+      .f_vector()
+      .into_iter()
+      .enumerate()
+      .map(|(k, count)| if k % 2 == 0 { count as isize } else { -(count as isize) })
+      .sum()
+  }
+
+  /// Returns the Betti numbers βₖ = dim Hₖ over the field `F`, one for each dimension of the
+  /// f-vector.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use cova_algebra::algebras::boolean::Boolean;
+  /// # use cova_space::complexes::{Complex, Simplex};
+  /// # let mut complex = Complex::new();
+  /// // The boundary of a triangle is a circle.
+  /// for edge in [[0, 1], [1, 2], [0, 2]] {
+  ///   complex.join_element(Simplex::new(1, edge.to_vec()));
+  /// }
+  /// assert_eq!(complex.betti_numbers::<Boolean>(), vec![1, 1]);
+  /// ```
+  pub fn betti_numbers<F: Field + Copy>(&self) -> Vec<usize> {
+    (0..self.dimension_index.len()).map(|k| self.homology::<F>(k).betti_number).collect()
   }
 
   /// Returns the direct faces of an element within this complex.
@@ -888,14 +1041,14 @@ impl<T: ComplexElement> Complex<T> {
   /// assert!(faces.iter().all(|face| face.id().is_some()));
   /// ```
   pub fn faces(&self, element: &T) -> Vec<T> {
-    element.id().map_or_else(Vec::new, |id| {
-      self
-        .attachment_lattice
-        .predecessors(id)
-        .into_iter()
-        .filter_map(|face_id| self.get_element(face_id).cloned())
-        .collect()
-    })
+    if element.id().is_none() {
+      return Vec::new();
+    }
+    let mut faces: Vec<T> =
+      element.faces().iter().filter_map(|face| self.find_equivalent_element(face)).collect();
+    faces.sort_unstable();
+    faces.dedup();
+    faces
   }
 
   /// Returns the direct cofaces of an element within this complex.
@@ -918,16 +1071,147 @@ impl<T: ComplexElement> Complex<T> {
   /// assert!(cofaces[0].same_content(&added_triangle));
   /// ```
   pub fn cofaces(&self, element: &T) -> Vec<T> {
-    element.id().map_or_else(Vec::new, |id| {
-      self
-        .attachment_lattice
-        .successors(id)
-        .into_iter()
-        .filter_map(|coface_id| self.get_element(coface_id).cloned())
-        .collect()
+    element.id().and_then(|id| self.coface_index.get(&id)).map_or_else(Vec::new, |ids| {
+      ids.iter().filter_map(|id| self.get_element(*id)).cloned().collect()
     })
   }
 
+  /// Returns the (open) star of a set of elements: every element having one of them as a face.
+  ///
+  /// The star St(S) is the union of the upsets of the elements of S in the face poset, including
+  /// the elements themselves. It is generally not closed under taking faces and is therefore
+  /// returned as a list of elements (sorted by their [`Ord`] implementation) rather than as a
+  /// complex; see [`Complex::closed_star`] for the smallest subcomplex containing it.
+  ///
+  /// # Panics
+  ///
+  /// Panics if an element of `elements` is not in the complex.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use cova_space::complexes::{Complex, ComplexElement, Simplex};
+  /// # let mut complex = Complex::new();
+  /// complex.join_element(Simplex::new(2, vec![0, 1, 2]));
+  ///
+  /// // The vertex 0, the two edges through it and the triangle.
+  /// let star = complex.star(&[Simplex::new(0, vec![0])]);
+  /// assert_eq!(star.len(), 4);
+  /// ```
+  pub fn star(&self, elements: &[T]) -> Vec<T> {
+    let mut star: Vec<T> =
+      self.star_ids(elements).into_iter().filter_map(|id| self.get_element(id)).cloned().collect();
+    star.sort_unstable();
+    star
+  }
+
+  /// Returns the closure of a set of elements: the smallest subcomplex containing them.
+  ///
+  /// The closure Cl(S) is the union of the downsets of the elements of S. Elements of the
+  /// returned complex keep the IDs they have in `self`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if an element of `elements` is not in the complex.
+  pub fn closure(&self, elements: &[T]) -> Self {
+    self.subcomplex_from_ids(self.closure_ids(&self.ids_of(elements)))
+  }
+
+  /// Returns the closed star of a set of elements: the closure of their star.
+  ///
+  /// This is the smallest subcomplex containing every element that has an element of S as a
+  /// face. Elements of the returned complex keep the IDs they have in `self`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if an element of `elements` is not in the complex.
+  pub fn closed_star(&self, elements: &[T]) -> Self {
+    self.subcomplex_from_ids(self.closure_ids(&self.star_ids(elements)))
+  }
+
+  /// Returns the link of a set of elements.
+  ///
+  /// The link is the part of the closed star that does not touch S:
+  ///
+  /// ```text
+  /// Lk(S) = Cl(St(S)) \ St(Cl(S))
+  /// ```
+  ///
+  /// For a single simplex σ of a simplicial complex this is the set of simplices τ with τ ∩ σ = ∅
+  /// and τ ∪ σ a simplex. Links describe the local structure around S: in a triangulated
+  /// d-manifold the link of every k-simplex is a (d-k-1)-sphere (or ball, on the boundary), which
+  /// is how manifold conditions are checked on meshes. Elements of the returned complex keep the
+  /// IDs they have in `self`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if an element of `elements` is not in the complex.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use cova_algebra::algebras::boolean::Boolean;
+  /// use cova_space::complexes::{Simplex, SimplicialComplex};
+  ///
+  /// // The boundary of a tetrahedron is a 2-sphere, so the link of a vertex is a circle.
+  /// let mut sphere = SimplicialComplex::new();
+  /// for face in [[0, 1, 2], [0, 1, 3], [0, 2, 3], [1, 2, 3]] {
+  ///   sphere.join_element(Simplex::new(2, face.to_vec()));
+  /// }
+  ///
+  /// let link = sphere.link(&[Simplex::new(0, vec![0])]);
+  /// assert_eq!(link.f_vector(), vec![3, 3]);
+  /// assert_eq!(link.homology::<Boolean>(1).betti_number, 1);
+  /// ```
+  pub fn link(&self, elements: &[T]) -> Self {
+    let closed_star = self.closure_ids(&self.star_ids(elements));
+    let touching: HashSet<usize> = self
+      .closure_ids(&self.ids_of(elements))
+      .into_iter()
+      .flat_map(|id| self.attachment_lattice.upset(id))
+      .collect();
+    self.subcomplex_from_ids(closed_star.difference(&touching).copied().collect())
+  }
+
+  /// Returns the IDs of `elements` in this complex.
+  fn ids_of(&self, elements: &[T]) -> Vec<usize> {
+    elements
+      .iter()
+      .map(|element| {
+        self
+          .find_equivalent_element(element)
+          .and_then(|existing| existing.id())
+          .expect("elements must belong to the complex")
+      })
+      .collect()
+  }
+
+  /// Returns the IDs of the star of `elements`.
+  fn star_ids(&self, elements: &[T]) -> Vec<usize> {
+    let star: HashSet<usize> =
+      self.ids_of(elements).into_iter().flat_map(|id| self.attachment_lattice.upset(id)).collect();
+    star.into_iter().collect()
+  }
+
+  /// Returns the IDs of the closure of the elements with the given IDs.
+  fn closure_ids(&self, ids: &[usize]) -> HashSet<usize> {
+    ids.iter().flat_map(|id| self.attachment_lattice.downset(*id)).collect()
+  }
+
+  /// Builds the subcomplex made of the elements with the given IDs, which must be closed under
+  /// taking faces. Elements keep their IDs.
+  fn subcomplex_from_ids(&self, ids: HashSet<usize>) -> Self {
+    let mut elements: Vec<&T> = ids.iter().filter_map(|id| self.get_element(*id)).collect();
+    // Faces are joined before their cofaces so that they keep their IDs rather than being
+    // created afresh.
+    elements.sort_unstable_by_key(|element| (element.dimension(), element.id()));
+    let mut subcomplex = Self::new();
+    for element in elements {
+      subcomplex.join_element(element.clone());
+    }
+    subcomplex
+  }
+
   /// Computes the k-dimensional homology of the complex over a field F.
   ///
   /// Homology measures the "holes" in a topological space at different dimensions:
@@ -954,10 +1238,12 @@ impl<T: ComplexElement> Complex<T> {
   ///
   /// # Algorithm
   ///
-  /// 1. **Compute Cycles**: Find kernel of boundary operator ∂ₖ: Cₖ → Cₖ₋₁
-  /// 2. **Compute Boundaries**: Find image of boundary operator ∂ₖ₊₁: Cₖ₊₁ → Cₖ
-  /// 3. **Quotient Space**: Compute basis for quotient space Zₖ/Bₖ
-  /// 4. **Return Homology**: Package result with Betti number and generators
+  /// 1. **Build Operators**: Assemble ∂ₖ and ∂ₖ₊₁ as sparse column matrices
+  /// 2. **Compute Cycles**: Column-reduce ∂ₖ; the recorded column operations of the zero columns
+  ///    give a basis of Zₖ
+  /// 3. **Compute Boundaries**: Column-reduce ∂ₖ₊₁; its pivot rows mark the cycles that are
+  ///    boundaries
+  /// 4. **Return Homology**: The remaining cycles form a basis of Zₖ/Bₖ
   ///
   /// # Special Cases
   ///
@@ -974,16 +1260,18 @@ impl<T: ComplexElement> Complex<T> {
   ///
   /// # Performance
   ///
-  /// - **Time**: O(n³) where n is the number of k-dimensional elements
-  /// - **Space**: O(n²) for storing boundary matrices
-  /// - **Bottleneck**: Matrix kernel and image computations
+  /// - **Time**: O(n³) in the worst case where n is the number of k-dimensional elements, but
+  ///   typically close to linear since boundary columns stay sparse during reduction
+  /// - **Space**: Proportional to the number of non-zero entries of ∂ₖ and ∂ₖ₊₁ (plus fill-in)
+  /// - **Bottleneck**: Column reduction of the sparse boundary matrices
   ///
   /// # Return Value
   ///
   /// Returns a [`Homology`] object containing:
   /// - `dimension`: The dimension k being computed
   /// - `betti_number`: The rank of Hₖ (number of independent k-dimensional holes)
-  /// - `homology_generators`: Basis vectors representing the homology classes
+  /// - `homology_generators`: Basis vectors representing the homology classes, indexed by
+  ///   [`Complex::basis`] (use [`Complex::homology_generator_chains`] to obtain them as chains)
   ///
   /// # Examples
   ///
@@ -1048,35 +1336,342 @@ impl<T: ComplexElement> Complex<T> {
   /// assert_eq!(h2.betti_number, 1); // One 2-dimensional hole (sphere interior)
   /// ```
   pub fn homology<F: Field + Copy>(&self, k: usize) -> Homology<F> {
-    let k_elements = {
-      let mut elements = self.elements_of_dimension(k);
-      elements.sort_unstable();
-      elements
-    };
-
-    if k_elements.is_empty() {
+    if self.elements_of_dimension(k).is_empty() {
       return Homology::trivial(k);
     }
 
-    let cycles = if k == 0 {
-      // Z₀ = C₀ (kernel of ∂₀: C₀ -> C₋₁ is C₀ itself).
-      let num_0_elements = k_elements.len();
-      let mut basis: Vec<DVector<F>> = Vec::with_capacity(num_0_elements);
-      for i in 0..num_0_elements {
-        let mut v_data = vec![F::zero(); num_0_elements];
-        v_data[i] = F::one();
-        basis.push(DVector::from_row_slice(&v_data));
-      }
-      basis
-    } else {
-      let boundary_k = self.get_boundary_matrix::<F>(k);
-      kernel(&boundary_k)
-    };
+    // ∂₀ is the zero map, so Z₀ = C₀ falls out of the same computation.
+    let boundary_k = self.get_sparse_boundary_matrix::<F>(k);
+    let boundary_k_plus_1 = self.get_sparse_boundary_matrix::<F>(k + 1);
+    let quotient_basis_vectors = kernel_modulo_image(&boundary_k, &boundary_k_plus_1);
+
+    Homology {
+      dimension:           k,
+      betti_number:        quotient_basis_vectors.len(),
+      homology_generators: quotient_basis_vectors,
+    }
+  }
+
+  /// Computes the k-dimensional reduced homology of the complex over a field F.
+  ///
+  /// Reduced homology is the homology of the augmented chain complex
+  ///
+  /// ```text
+  /// ... → C₁ → C₀ → F → 0
+  /// ```
+  ///
+  /// where the augmentation ε: C₀ → F sends every vertex to 1. It agrees with
+  /// [`Complex::homology`] in positive dimensions, while H̃₀ = ker(ε) / im(∂₁) counts the
+  /// connected components minus one. Contractible complexes therefore have vanishing reduced
+  /// homology in every dimension.
+  ///
+  /// # Return Value
+  ///
+  /// Generators of H̃₀ are differences of vertices in distinct components, expressed in the same
+  /// basis of sorted 0-elements as [`Complex::homology`]. An empty complex yields trivial groups.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use cova_algebra::algebras::boolean::Boolean;
+  /// # use cova_space::complexes::{Complex, Simplex};
+  /// # let mut complex = Complex::new();
+  /// complex.join_element(Simplex::new(1, vec![0, 1]));
+  /// complex.join_element(Simplex::new(0, vec![2]));
+  ///
+  /// assert_eq!(complex.homology::<Boolean>(0).betti_number, 2);
+  /// assert_eq!(complex.reduced_homology::<Boolean>(0).betti_number, 1);
+  /// ```
+  pub fn reduced_homology<F: Field + Copy>(&self, k: usize) -> Homology<F> {
+    if k > 0 {
+      return self.homology(k);
+    }
+    let num_vertices = self.elements_of_dimension(0).len();
+    if num_vertices == 0 {
+      return Homology::trivial(0);
+    }
+
+    let augmentation =
+      SparseMatrix::from_columns(1, (0..num_vertices).map(|_| vec![(0, F::one())]).collect());
+    let boundary_1 = self.get_sparse_boundary_matrix::<F>(1);
+    let quotient_basis_vectors = kernel_modulo_image(&augmentation, &boundary_1);
+
+    Homology {
+      dimension:           0,
+      betti_number:        quotient_basis_vectors.len(),
+      homology_generators: quotient_basis_vectors,
+    }
+  }
+
+  /// Expresses the generators of a homology group as chains of elements of this complex.
+  ///
+  /// The coefficient vectors in [`Homology::homology_generators`] are indexed by
+  /// [`Complex::basis`]; this maps each of them back to a [`Chain`] containing the actual
+  /// elements (with IDs) in its support. This is what is needed to draw the loops and voids found
+  /// by [`Complex::homology`] or [`Complex::reduced_homology`].
+  ///
+  /// # Panics
+  ///
+  /// Panics if the generators do not have one coefficient per element of dimension
+  /// `homology.dimension`, e.g. when `homology` was computed on a different complex.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use cova_algebra::algebras::boolean::Boolean;
+  /// use cova_space::complexes::{ComplexElement, Simplex, SimplicialComplex};
+  ///
+  /// // A filled triangle with a hollow square attached along the edge {1, 2}.
+  /// let mut complex = SimplicialComplex::new();
+  /// complex.join_element(Simplex::new(2, vec![0, 1, 2]));
+  /// for edge in [[1, 3], [3, 4], [2, 4]] {
+  ///   complex.join_element(Simplex::new(1, edge.to_vec()));
+  /// }
+  ///
+  /// let h1 = complex.homology::<Boolean>(1);
+  /// let loops = complex.homology_generator_chains(&h1);
+  /// assert_eq!(loops.len(), 1);
+  ///
+  /// // The loop is a cycle made of edges of the complex, going around the square.
+  /// assert!(loops[0].items.len() >= 4);
+  /// assert!(loops[0].items.iter().all(|edge| edge.dimension() == 1 && edge.id().is_some()));
+  /// assert!(loops[0].boundary().items.is_empty());
+  /// ```
+  pub fn homology_generator_chains<F: Field + Copy>(
+    &self,
+    homology: &Homology<F>,
+  ) -> Vec<Chain<'_, Self, F>> {
+    let basis = self.basis(homology.dimension);
+    homology
+      .homology_generators
+      .iter()
+      .map(|generator| Chain::from_coeff_vector(self, &basis, generator))
+      .collect()
+  }
+
+  /// Computes the k-th cohomology group with coefficients in field F.
+  ///
+  /// Cohomology is the dual of homology: a k-cochain assigns a coefficient to every k-element,
+  /// and the coboundary δᵏ: Cᵏ → Cᵏ⁺¹ is the transpose of ∂ₖ₊₁, so
+  ///
+  /// ```text
+  /// Hᵏ(K) = Zᵏ(K) / Bᵏ(K) = ker(δᵏ) / im(δᵏ⁻¹)
+  /// ```
+  ///
+  /// Over a field, Hᵏ has the same dimension as Hₖ. The representatives, however, are cocycles:
+  /// functions on the k-elements that vanish on every boundary. These are the inputs for
+  /// circular coordinates (from H¹) and cup products, and they live in the same cochain spaces
+  /// as the coboundary of a [`crate::sheaf::Sheaf`] with constant stalks.
+  ///
+  /// # Cochain Basis
+  ///
+  /// Each representative is a vector indexed by the k-elements sorted by their [`Ord`]
+  /// implementation, i.e. by the columns of [`Complex::get_coboundary_matrix`].
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use cova_algebra::algebras::boolean::Boolean;
+  /// use cova_space::complexes::{Simplex, SimplicialComplex};
+  ///
+  /// let mut complex = SimplicialComplex::new();
+  /// complex.join_element(Simplex::new(1, vec![0, 1]));
+  /// complex.join_element(Simplex::new(1, vec![1, 2]));
+  /// complex.join_element(Simplex::new(1, vec![0, 2]));
+  ///
+  /// let h1 = complex.cohomology::<Boolean>(1);
+  /// assert_eq!(h1.betti_number, 1);
+  ///
+  /// // The representative is a cocycle: its coboundary vanishes.
+  /// let delta = complex.get_coboundary_matrix::<Boolean>(1);
+  /// assert!((delta * &h1.cocycle_representatives[0]).iter().all(|c| *c == Boolean(false)));
+  /// ```
+  pub fn cohomology<F: Field + Copy>(&self, k: usize) -> Cohomology<F> {
+    if self.elements_of_dimension(k).is_empty() {
+      return Cohomology::trivial(k);
+    }
+
+    let coboundary_k = self.get_sparse_coboundary_matrix::<F>(k);
+    let coboundary_k_minus_1 = self.get_sparse_boundary_matrix::<F>(k).transpose();
+    let cocycle_representatives = kernel_modulo_image(&coboundary_k, &coboundary_k_minus_1);
+
+    Cohomology {
+      dimension: k,
+      betti_number: cocycle_representatives.len(),
+      cocycle_representatives,
+    }
+  }
+
+  /// Computes the k-th homology group with integer coefficients, including torsion.
+  ///
+  /// Over a field, homology only records Betti numbers. Over ℤ the homology group decomposes as
+  ///
+  /// ```text
+  /// Hₖ(K; ℤ) ≅ ℤ^β ⊕ ℤ/d₁ ⊕ ... ⊕ ℤ/dₘ
+  /// ```
+  ///
+  /// where the torsion coefficients dᵢ > 1 are the invariant factors of ∂ₖ₊₁ other than 1, and
+  /// β = dim Cₖ - rank ∂ₖ - rank ∂ₖ₊₁ is the free rank. Both are read off the Smith normal forms
+  /// of the integer boundary matrices (see [`cova_algebra::tensors::smith`]). Torsion is what
+  /// distinguishes, e.g., the projective plane (H₁ = ℤ/2) from a disk, and it explains why Betti
+  /// numbers over ℤ/2ℤ and over ℚ can disagree for non-orientable spaces.
+  ///
+  /// # Performance
+  ///
+  /// The Smith normal form is computed on dense integer matrices, so this is intended for small
+  /// to medium complexes. Use [`Complex::homology`] when only Betti numbers over a field are
+  /// needed.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use cova_space::complexes::{Simplex, SimplicialComplex};
+  ///
+  /// // The minimal 6-vertex triangulation of the real projective plane.
+  /// let mut complex = SimplicialComplex::new();
+  /// for triangle in [
+  ///   [0, 1, 2],
+  ///   [0, 2, 3],
+  ///   [0, 3, 4],
+  ///   [0, 4, 5],
+  ///   [0, 1, 5],
+  ///   [1, 2, 4],
+  ///   [1, 3, 4],
+  ///   [1, 3, 5],
+  ///   [2, 3, 5],
+  ///   [2, 4, 5],
+  /// ] {
+  ///   complex.join_element(Simplex::new(2, triangle.to_vec()));
+  /// }
+  ///
+  /// let h1 = complex.integral_homology(1);
+  /// assert_eq!(h1.free_rank, 0);
+  /// assert_eq!(h1.torsion_coefficients, vec![2]); // H₁(RP²; ℤ) = ℤ/2
+  ///
+  /// let h2 = complex.integral_homology(2);
+  /// assert_eq!(h2.free_rank, 0); // RP² is non-orientable
+  /// ```
+  pub fn integral_homology(&self, k: usize) -> IntegralHomology {
+    let num_k_elements = self.elements_of_dimension(k).len();
+    if num_k_elements == 0 {
+      return IntegralHomology::trivial(k);
+    }
 
-    let boundary_k_plus_1 = self.get_boundary_matrix::<F>(k + 1);
-    let boundaries = image(&boundary_k_plus_1);
+    let rank_k = invariant_factors(&self.get_integral_boundary_matrix(k)).len();
+    let factors_k_plus_1 = invariant_factors(&self.get_integral_boundary_matrix(k + 1));
+
+    IntegralHomology {
+      dimension:            k,
+      free_rank:            num_k_elements - rank_k - factors_k_plus_1.len(),
+      torsion_coefficients: factors_k_plus_1.into_iter().filter(|d| *d != 1).collect(),
+    }
+  }
 
-    let quotient_basis_vectors = compute_quotient_basis(&boundaries, &cycles);
+  /// Computes the k-th relative homology group Hₖ(K, A) of the complex relative to a subcomplex.
+  ///
+  /// Elements of `subcomplex` are matched to elements of `self` by content, so `subcomplex` may
+  /// be built independently (e.g. the boundary of a mesh). See
+  /// [`Complex::relative_homology_by`] for the mathematical details.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `subcomplex` contains an element that is not in `self`.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use cova_algebra::algebras::boolean::Boolean;
+  /// use cova_space::complexes::{Simplex, SimplicialComplex};
+  ///
+  /// // A filled triangle relative to its boundary circle is a 2-sphere.
+  /// let mut disk = SimplicialComplex::new();
+  /// disk.join_element(Simplex::new(2, vec![0, 1, 2]));
+  ///
+  /// let mut circle = SimplicialComplex::new();
+  /// for edge in [[0, 1], [1, 2], [0, 2]] {
+  ///   circle.join_element(Simplex::new(1, edge.to_vec()));
+  /// }
+  ///
+  /// assert_eq!(disk.relative_homology::<Boolean>(&circle, 0).betti_number, 0);
+  /// assert_eq!(disk.relative_homology::<Boolean>(&circle, 1).betti_number, 0);
+  /// assert_eq!(disk.relative_homology::<Boolean>(&circle, 2).betti_number, 1);
+  /// ```
+  pub fn relative_homology<F: Field + Copy>(&self, subcomplex: &Self, k: usize) -> Homology<F> {
+    assert!(
+      subcomplex.elements.values().all(|element| self.find_equivalent_element(element).is_some()),
+      "subcomplex contains elements that are not in the complex"
+    );
+    self.relative_homology_by(|element| subcomplex.find_equivalent_element(element).is_some(), k)
+  }
+
+  /// Computes the k-th relative homology group Hₖ(K, A), where the subcomplex A consists of the
+  /// elements satisfying `in_subcomplex`.
+  ///
+  /// # Mathematical Background
+  ///
+  /// The relative chain groups are the quotients Cₖ(K, A) = Cₖ(K) / Cₖ(A), which have a basis
+  /// given by the k-elements of K that are not in A. Because A is closed under taking faces, ∂
+  /// maps Cₖ(A) into Cₖ₋₁(A) and descends to the quotient: the relative boundary ∂ₖ of an element
+  /// is its ordinary boundary with all faces in A dropped. Then
+  ///
+  /// ```text
+  /// Hₖ(K, A) = ker(∂ₖ: Cₖ(K, A) → Cₖ₋₁(K, A)) / im(∂ₖ₊₁: Cₖ₊₁(K, A) → Cₖ(K, A))
+  /// ```
+  ///
+  /// Relative cycles are chains whose boundary lies in A; for example, an edge path between two
+  /// vertices of A is a relative 1-cycle. For a nonempty A, Hₖ(K, A) is the reduced homology of
+  /// the quotient space K/A.
+  ///
+  /// # Generators
+  ///
+  /// The generators are vectors indexed by the k-elements not in A, sorted by their [`Ord`]
+  /// implementation (the columns of [`Complex::get_relative_boundary_matrix`]).
+  ///
+  /// # Panics
+  ///
+  /// Panics if the elements satisfying `in_subcomplex` do not form a subcomplex, i.e. if some
+  /// face of such an element does not satisfy it.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use cova_algebra::algebras::boolean::Boolean;
+  /// use cova_space::complexes::{ComplexElement, Simplex, SimplicialComplex};
+  ///
+  /// // A path 0 - 1 - 2 relative to its two endpoints has one relative 1-cycle.
+  /// let mut path = SimplicialComplex::new();
+  /// path.join_element(Simplex::new(1, vec![0, 1]));
+  /// path.join_element(Simplex::new(1, vec![1, 2]));
+  ///
+  /// let endpoints = |s: &Simplex| s.dimension() == 0 && s.vertices() != [1];
+  /// assert_eq!(path.relative_homology_by::<Boolean>(endpoints, 0).betti_number, 0);
+  /// assert_eq!(path.relative_homology_by::<Boolean>(endpoints, 1).betti_number, 1);
+  /// ```
+  pub fn relative_homology_by<F: Field + Copy>(
+    &self,
+    in_subcomplex: impl Fn(&T) -> bool,
+    k: usize,
+  ) -> Homology<F> {
+    assert!(
+      self
+        .elements
+        .values()
+        .filter(|element| in_subcomplex(element))
+        .all(|element| self.faces(element).iter().all(&in_subcomplex)),
+      "elements in the subcomplex must have all of their faces in the subcomplex"
+    );
+
+    let outside = |element: &T| !in_subcomplex(element);
+    if !self.elements_of_dimension(k).iter().any(outside) {
+      return Homology::trivial(k);
+    }
+
+    let (nrows_k, columns_k) = self.boundary_columns::<F>(k, outside);
+    let (nrows_k_plus_1, columns_k_plus_1) = self.boundary_columns::<F>(k + 1, outside);
+    let quotient_basis_vectors = kernel_modulo_image(
+      &SparseMatrix::from_columns(nrows_k, columns_k),
+      &SparseMatrix::from_columns(nrows_k_plus_1, columns_k_plus_1),
+    );
 
     Homology {
       dimension:           k,
@@ -1085,6 +1680,35 @@ impl<T: ComplexElement> Complex<T> {
     }
   }
 
+  /// Constructs the relative boundary matrix ∂ₖ: Cₖ(K, A) → Cₖ₋₁(K, A), where the subcomplex A
+  /// consists of the elements satisfying `in_subcomplex`.
+  ///
+  /// Rows and columns are indexed by the (k-1)- and k-elements not in A, sorted by their [`Ord`]
+  /// implementation. This is the submatrix of [`Complex::get_boundary_matrix`] obtained by
+  /// deleting the rows and columns of elements in A.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use cova_algebra::algebras::boolean::Boolean;
+  /// use cova_space::complexes::{ComplexElement, Simplex, SimplicialComplex};
+  ///
+  /// let mut complex = SimplicialComplex::new();
+  /// complex.join_element(Simplex::new(1, vec![0, 1]));
+  ///
+  /// // Relative to vertex 0, the edge's boundary is just vertex 1.
+  /// let boundary = complex.get_relative_boundary_matrix::<Boolean>(1, |s| s.vertices() == [0]);
+  /// assert_eq!((boundary.nrows(), boundary.ncols()), (1, 1));
+  /// ```
+  pub fn get_relative_boundary_matrix<F: Field + Copy>(
+    &self,
+    k: usize,
+    in_subcomplex: impl Fn(&T) -> bool,
+  ) -> DMatrix<F> {
+    let (nrows, columns) = self.boundary_columns::<F>(k, |element| !in_subcomplex(element));
+    SparseMatrix::from_columns(nrows, columns).to_dense()
+  }
+
   /// Constructs the boundary matrix ∂ₖ: Cₖ → Cₖ₋₁ for the k-th boundary operator.
   ///
   /// The boundary matrix is the matrix representation of the linear map that takes
@@ -1131,9 +1755,10 @@ impl<T: ComplexElement> Complex<T> {
   ///
   /// # Implementation Details
   ///
-  /// This method uses the [`ComplexElement::boundary_with_orientations`] method
-  /// to get the oriented boundary of each element, then constructs the matrix
-  /// by mapping faces to their positions in the codomain basis.
+  /// This method assembles the sparse matrix from
+  /// [`Complex::get_sparse_boundary_matrix`], which uses the
+  /// [`ComplexElement::boundary_with_orientations`] method to get the oriented boundary of each
+  /// element and maps faces to their positions in the codomain basis, and then densifies it.
   ///
   /// # Performance
   ///
@@ -1179,34 +1804,114 @@ impl<T: ComplexElement> Complex<T> {
   /// ```
   pub fn get_boundary_matrix<F: Field + Copy>(&self, k: usize) -> DMatrix<F>
   where T: ComplexElement {
-    let domain_basis = self.elements_of_dimension(k);
-    let codomain_basis = self.elements_of_dimension(k.saturating_sub(1));
+    self.get_sparse_boundary_matrix(k).to_dense()
+  }
 
-    if domain_basis.is_empty() || codomain_basis.is_empty() {
-      // Return appropriate empty matrix
-      return DMatrix::<F>::zeros(codomain_basis.len(), domain_basis.len());
+  /// Constructs the boundary matrix ∂ₖ: Cₖ → Cₖ₋₁ in sparse column form.
+  ///
+  /// This is the sparse counterpart of [`Complex::get_boundary_matrix`] and uses the same
+  /// (sorted) bases for rows and columns, so the two representations agree entry by entry. Each
+  /// column stores only the faces of the corresponding element, which keeps memory linear in the
+  /// number of cells and is what [`Complex::homology`] eliminates on.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use cova_algebra::algebras::boolean::Boolean;
+  /// use cova_space::complexes::{Complex, Simplex};
+  ///
+  /// let mut complex = Complex::new();
+  /// complex.join_element(Simplex::new(2, vec![0, 1, 2]));
+  ///
+  /// let boundary_1 = complex.get_sparse_boundary_matrix::<Boolean>(1);
+  /// assert_eq!((boundary_1.nrows(), boundary_1.ncols()), (3, 3));
+  /// assert_eq!(boundary_1.nnz(), 6); // two vertices per edge
+  /// assert_eq!(boundary_1.to_dense(), complex.get_boundary_matrix::<Boolean>(1));
+  /// ```
+  pub fn get_sparse_boundary_matrix<F: Field + Copy>(&self, k: usize) -> SparseMatrix<F> {
+    let (nrows, columns) = self.boundary_columns::<F>(k, |_| true);
+    SparseMatrix::from_columns(nrows, columns)
+  }
+
+  /// Constructs the boundary matrix ∂ₖ: Cₖ → Cₖ₋₁ with integer coefficients.
+  ///
+  /// This uses the same sorted bases as [`Complex::get_boundary_matrix`] and is the input for
+  /// [`Complex::integral_homology`].
+  pub fn get_integral_boundary_matrix(&self, k: usize) -> DMatrix<i64> {
+    let (nrows, columns) = self.boundary_columns::<i64>(k, |_| true);
+    let mut matrix = DMatrix::zeros(nrows, columns.len());
+    for (col_idx, column) in columns.into_iter().enumerate() {
+      for (row_idx, coeff) in column {
+        matrix[(row_idx, col_idx)] += coeff;
+      }
     }
+    matrix
+  }
 
-    let mut matrix = DMatrix::<F>::zeros(codomain_basis.len(), domain_basis.len());
+  /// Returns the number of rows of ∂ₖ and its columns as `(row, coefficient)` entries.
+  ///
+  /// Rows and columns are indexed by the (k-1)- and k-elements sorted by their [`Ord`]
+  /// implementation.
+  /// Only elements satisfying `include` are used as basis elements, which yields the relative
+  /// boundary matrices of [`Complex::relative_homology_by`].
+  fn boundary_columns<R: Ring + Copy>(
+    &self,
+    k: usize,
+    include: impl Fn(&T) -> bool,
+  ) -> (usize, Vec<Vec<(usize, R)>>) {
+    let mut domain_basis = self.elements_of_dimension(k);
+    domain_basis.retain(&include);
+    domain_basis.sort_unstable();
+    let mut codomain_basis = self.elements_of_dimension(k.saturating_sub(1));
+    codomain_basis.retain(&include);
+    codomain_basis.sort_unstable();
 
-    // Create a map from elements to their position in the codomain basis
     let basis_map_for_codomain: HashMap<&T, usize> =
       codomain_basis.iter().enumerate().map(|(i, s)| (s, i)).collect();
-    let num_codomain_elements = codomain_basis.len();
-
-    for (col_idx, element_from_domain) in domain_basis.iter().enumerate() {
-      // Compute boundary using the Topology trait implementation
-      let boundary_chain: Chain<Self, F> = self.boundary(element_from_domain);
 
-      // Convert the chain to a coefficient vector representing this column.
-      let col_vector =
-        boundary_chain.to_coeff_vector(&basis_map_for_codomain, num_codomain_elements);
+    let columns = domain_basis
+      .iter()
+      .map(|element| {
+        let boundary_chain: Chain<Self, R> = self.boundary(element);
+        boundary_chain
+          .items
+          .iter()
+          .zip(boundary_chain.coefficients)
+          .filter_map(|(face, coeff)| basis_map_for_codomain.get(face).map(|&row| (row, coeff)))
+          .collect()
+      })
+      .collect();
+
+    (codomain_basis.len(), columns)
+  }
 
-      // Fill the `col_idx`-th column of the boundary matrix.
-      matrix.set_column(col_idx, &col_vector);
-    }
+  /// Constructs the coboundary matrix δᵏ: Cᵏ → Cᵏ⁺¹, the transpose of ∂ₖ₊₁.
+  ///
+  /// Rows are indexed by the (k+1)-elements and columns by the k-elements, both sorted by their
+  /// [`Ord`] implementation. Entry (σ, τ) is the coefficient of τ in ∂σ.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use cova_algebra::algebras::boolean::Boolean;
+  /// use cova_space::complexes::{Complex, Simplex};
+  ///
+  /// let mut complex = Complex::new();
+  /// complex.join_element(Simplex::new(2, vec![0, 1, 2]));
+  ///
+  /// // δ⁰: C⁰ → C¹ maps functions on the 3 vertices to functions on the 3 edges.
+  /// let delta_0 = complex.get_coboundary_matrix::<Boolean>(0);
+  /// assert_eq!(delta_0, complex.get_boundary_matrix::<Boolean>(1).transpose());
+  /// ```
+  pub fn get_coboundary_matrix<F: Field + Copy>(&self, k: usize) -> DMatrix<F> {
+    self.get_sparse_coboundary_matrix(k).to_dense()
+  }
 
-    matrix
+  /// Constructs the coboundary matrix δᵏ: Cᵏ → Cᵏ⁺¹ in sparse column form.
+  ///
+  /// See [`Complex::get_coboundary_matrix`] for the basis conventions.
+  pub fn get_sparse_coboundary_matrix<F: Field + Copy>(&self, k: usize) -> SparseMatrix<F> {
+    self.get_sparse_boundary_matrix(k + 1).transpose()
   }
 }
 
@@ -1329,32 +2034,26 @@ impl<T: ComplexElement> Poset for Complex<T> {
   }
 
   fn successors(&self, a: Self::Item) -> std::collections::HashSet<Self::Item> {
-    if let Some(id) = a.id() {
-      self
-        .attachment_lattice
-        .successors(id)
-        .into_iter()
-        .filter_map(|succ_id| self.get_element(succ_id).cloned())
-        .collect()
-    } else {
-      std::collections::HashSet::new()
-    }
+    // The covering relation of the face poset is exactly the codimension-one face relation.
+    self.cofaces(&a).into_iter().collect()
   }
 
   fn predecessors(&self, a: Self::Item) -> std::collections::HashSet<Self::Item> {
-    if let Some(id) = a.id() {
-      self
-        .attachment_lattice
-        .predecessors(id)
-        .into_iter()
-        .filter_map(|pred_id| self.get_element(pred_id).cloned())
-        .collect()
-    } else {
-      std::collections::HashSet::new()
-    }
+    self.faces(&a).into_iter().collect()
   }
 }
 
+/// Returns the integer incidence number `incidence` as an element of the ring `R`, i.e. the sum of
+/// `|incidence|` copies of ±1. Incidences that vanish in `R`, such as 2 over
+/// [`Boolean`](cova_algebra::algebras::boolean::Boolean), map to zero.
+pub(crate) fn incidence_coefficient<R: Ring + Copy>(incidence: i32) -> R {
+  let mut coefficient = R::zero();
+  for _ in 0..incidence.unsigned_abs() {
+    coefficient += R::one();
+  }
+  if incidence < 0 { -coefficient } else { coefficient }
+}
+
 /// Implementation of [`Topology`] for complexes.
 ///
 /// Provides topological operations that integrate with the broader framework:
@@ -1374,28 +2073,34 @@ impl<T: ComplexElement> Topology for Complex<T> {
       return Chain::new(self);
     }
 
-    let mut boundary_chain_items = Vec::new();
-    let mut boundary_chain_coeffs = Vec::new();
-
-    // Use the element-specific boundary computation with orientations
-    let faces_with_orientations = item.boundary_with_orientations();
+    let mut boundary_chain_items: Vec<T> = Vec::new();
+    let mut boundary_chain_coeffs: Vec<R> = Vec::new();
 
-    for (face, orientation) in faces_with_orientations {
+    // Use the element-specific boundary computation with incidence numbers. A face may be
+    // listed more than once, e.g. by a cell whose attaching map wraps around it, so the
+    // incidences are summed per face before zero coefficients are dropped.
+    for (face, incidence) in item.boundary_with_orientations() {
       // Find the corresponding element in the complex that matches this face's content
-      if let Some(complex_face) = self.find_equivalent_element(&face) {
-        // Use the orientation coefficient from the element-specific boundary operator
-        let coeff = if orientation > 0 {
-          R::one()
-        } else if orientation < 0 {
-          -R::one()
-        } else {
-          continue; // Skip faces with zero coefficient
-        };
+      let Some(complex_face) = self.find_equivalent_element(&face) else {
+        continue;
+      };
+      let coeff = incidence_coefficient::<R>(incidence);
+      if let Some(index) =
+        boundary_chain_items.iter().position(|existing| *existing == complex_face)
+      {
+        boundary_chain_coeffs[index] += coeff;
+      } else {
         boundary_chain_items.push(complex_face);
         boundary_chain_coeffs.push(coeff);
       }
     }
 
+    let (boundary_chain_items, boundary_chain_coeffs) = boundary_chain_items
+      .into_iter()
+      .zip(boundary_chain_coeffs)
+      .filter(|(_, coeff)| !coeff.is_zero())
+      .unzip();
+
     Chain::from_items_and_coeffs(self, boundary_chain_items, boundary_chain_coeffs)
   }
 }
@@ -1686,4 +2391,319 @@ mod tests {
     assert_eq!(h0_cube.betti_number, 1); // One connected component
     assert_eq!(h1_cube.betti_number, 0); // No 1D holes (filled)
   }
+
+  #[test]
+  fn test_sparse_boundary_matrices() {
+    let mut complex = SimplicialComplex::new();
+    for face in [[0, 1, 2], [0, 1, 3], [0, 2, 3], [1, 2, 3]] {
+      complex.join_element(Simplex::new(2, face.to_vec()));
+    }
+
+    for k in 0..=3 {
+      let sparse = complex.get_sparse_boundary_matrix::<Boolean>(k);
+      assert_eq!(sparse.to_dense(), complex.get_boundary_matrix::<Boolean>(k));
+    }
+
+    // ∂₁∂₂ = 0
+    let d1 = complex.get_sparse_boundary_matrix::<Boolean>(1);
+    let d2 = complex.get_sparse_boundary_matrix::<Boolean>(2);
+    assert_eq!(d1.mul(&d2).nnz(), 0);
+
+    // The homology generator of the sphere is a cycle.
+    let h2 = complex.homology::<Boolean>(2);
+    assert_eq!(h2.betti_number, 1);
+    assert!(d2.mul_vector(&h2.homology_generators[0]).iter().all(|c| *c == Boolean(false)));
+  }
+
+  #[test]
+  fn test_content_and_dimension_indices() {
+    let mut complex = SimplicialComplex::new();
+    for i in 0..20 {
+      complex.join_element(Simplex::new(2, vec![i, i + 1, i + 2]));
+    }
+
+    // 20 triangles on a path of 22 vertices: edges {i, i+1} and {i, i+2}.
+    assert_eq!(complex.elements_of_dimension(0).len(), 22);
+    assert_eq!(complex.elements_of_dimension(1).len(), 21 + 20);
+    assert_eq!(complex.elements_of_dimension(2).len(), 20);
+    assert_eq!(complex.elements_of_dimension(3).len(), 0);
+    assert_eq!(complex.max_dimension(), 2);
+    assert_eq!(complex.elements.len(), 22 + 41 + 20);
+
+    // Lookups by content resolve to the stored element regardless of the ID carried.
+    let edge = complex.join_element(Simplex::new(1, vec![5, 6]).with_id(1000));
+    assert_ne!(edge.id(), Some(1000));
+    assert_eq!(complex.elements.len(), 22 + 41 + 20);
+
+    let faces = complex.faces(&edge);
+    assert_eq!(faces.len(), 2);
+    assert!(faces.iter().all(|face| face.id().is_some() && face.dimension() == 0));
+
+    // The edge {5, 6} lies in triangles {4, 5, 6} and {5, 6, 7}.
+    let cofaces = complex.cofaces(&edge);
+    assert_eq!(cofaces.len(), 2);
+    assert_eq!(complex.successors(edge.clone()).len(), 2);
+    assert_eq!(complex.predecessors(edge.clone()).len(), 2);
+    assert_eq!(complex.upset(edge).len(), 3);
+  }
+
+  #[test]
+  fn test_cohomology_matches_homology() {
+    // A hollow tetrahedron (sphere) with an extra loop attached at a vertex.
+    let mut complex = SimplicialComplex::new();
+    for face in [[0, 1, 2], [0, 1, 3], [0, 2, 3], [1, 2, 3]] {
+      complex.join_element(Simplex::new(2, face.to_vec()));
+    }
+    for edge in [[3, 4], [4, 5], [3, 5]] {
+      complex.join_element(Simplex::new(1, edge.to_vec()));
+    }
+
+    for k in 0..=3 {
+      let homology = complex.homology::<Boolean>(k);
+      let cohomology = complex.cohomology::<Boolean>(k);
+      assert_eq!(cohomology.dimension, k);
+      assert_eq!(cohomology.betti_number, homology.betti_number, "dimension {k}");
+
+      let coboundary = complex.get_coboundary_matrix::<Boolean>(k);
+      for cocycle in &cohomology.cocycle_representatives {
+        assert_eq!(cocycle.len(), complex.elements_of_dimension(k).len());
+        assert!((&coboundary * cocycle).iter().all(|c| *c == Boolean(false)));
+      }
+    }
+    assert_eq!(complex.cohomology::<Boolean>(1).betti_number, 1);
+    assert_eq!(complex.cohomology::<Boolean>(2).betti_number, 1);
+  }
+
+  #[test]
+  fn test_integral_homology_torsion() {
+    // Minimal triangulation of the projective plane.
+    let mut rp2 = SimplicialComplex::new();
+    for triangle in [
+      [0, 1, 2],
+      [0, 2, 3],
+      [0, 3, 4],
+      [0, 4, 5],
+      [0, 1, 5],
+      [1, 2, 4],
+      [1, 3, 4],
+      [1, 3, 5],
+      [2, 3, 5],
+      [2, 4, 5],
+    ] {
+      rp2.join_element(Simplex::new(2, triangle.to_vec()));
+    }
+
+    assert_eq!(rp2.integral_homology(0), IntegralHomology {
+      dimension:            0,
+      free_rank:            1,
+      torsion_coefficients: vec![],
+    });
+    let h1 = rp2.integral_homology(1);
+    assert_eq!((h1.free_rank, h1.torsion_coefficients.clone()), (0, vec![2]));
+    assert!(h1.has_torsion());
+    assert_eq!(rp2.integral_homology(2), IntegralHomology::trivial(2));
+
+    // Torsion shows up as a difference between Betti numbers over ℤ/2ℤ and over ℚ.
+    assert_eq!(rp2.homology::<Boolean>(1).betti_number, 1);
+    assert_eq!(rp2.homology::<f64>(1).betti_number, 0);
+  }
+
+  #[test]
+  fn test_integral_homology_torus() {
+    // 3×3 grid with opposite sides identified, each square split into two triangles.
+    let mut torus = SimplicialComplex::new();
+    let vertex = |i: usize, j: usize| 3 * (i % 3) + (j % 3);
+    for i in 0..3 {
+      for j in 0..3 {
+        let (a, b, c, d) = (vertex(i, j), vertex(i + 1, j), vertex(i, j + 1), vertex(i + 1, j + 1));
+        let mut lower = vec![a, b, d];
+        let mut upper = vec![a, c, d];
+        lower.sort_unstable();
+        upper.sort_unstable();
+        torus.join_element(Simplex::new(2, lower));
+        torus.join_element(Simplex::new(2, upper));
+      }
+    }
+
+    let ranks: Vec<usize> = (0..=2).map(|k| torus.integral_homology(k).free_rank).collect();
+    assert_eq!(ranks, vec![1, 2, 1]);
+    assert!((0..=2).all(|k| !torus.integral_homology(k).has_torsion()));
+  }
+
+  #[test]
+  fn test_relative_homology() {
+    // A 2×2 block of squares on a 3×3 grid of vertices, relative to its outer boundary.
+    let mut disk = CubicalComplex::new();
+    for square in [[0, 1, 3, 4], [1, 2, 4, 5], [3, 4, 6, 7], [4, 5, 7, 8]] {
+      disk.join_element(Cube::square(square));
+    }
+    assert_eq!(disk.homology::<Boolean>(2).betti_number, 0);
+
+    // The boundary of the 2×2 block of squares is the outer cycle of eight edges.
+    let mut boundary = CubicalComplex::new();
+    for edge in [[0, 1], [1, 2], [2, 5], [5, 8], [7, 8], [6, 7], [3, 6], [0, 3]] {
+      boundary.join_element(Cube::edge(edge[0], edge[1]));
+    }
+
+    // A disk relative to its boundary circle has the homology of a sphere (reduced).
+    let relative: Vec<usize> =
+      (0..=2).map(|k| disk.relative_homology::<Boolean>(&boundary, k).betti_number).collect();
+    assert_eq!(relative, vec![0, 0, 1]);
+
+    // Relative to the empty subcomplex we recover absolute homology.
+    for k in 0..=2 {
+      assert_eq!(
+        disk.relative_homology_by::<Boolean>(|_| false, k).betti_number,
+        disk.homology::<Boolean>(k).betti_number
+      );
+    }
+  }
+
+  #[test]
+  #[should_panic(expected = "must have all of their faces")]
+  fn test_relative_homology_requires_subcomplex() {
+    let mut complex = SimplicialComplex::new();
+    complex.join_element(Simplex::new(1, vec![0, 1]));
+    complex.relative_homology_by::<Boolean>(|s| s.dimension() == 1, 1);
+  }
+
+  #[test]
+  fn test_reduced_homology_and_euler_characteristic() {
+    // Two components: a hollow tetrahedron and a separate circle.
+    let mut complex = SimplicialComplex::new();
+    for face in [[0, 1, 2], [0, 1, 3], [0, 2, 3], [1, 2, 3]] {
+      complex.join_element(Simplex::new(2, face.to_vec()));
+    }
+    for edge in [[4, 5], [5, 6], [4, 6]] {
+      complex.join_element(Simplex::new(1, edge.to_vec()));
+    }
+
+    assert_eq!(complex.f_vector(), vec![7, 9, 4]);
+    assert_eq!(complex.euler_characteristic(), 2);
+
+    let betti: Vec<usize> = (0..=2).map(|k| complex.homology::<Boolean>(k).betti_number).collect();
+    assert_eq!(betti, vec![2, 1, 1]);
+    let alternating_sum: isize = betti
+      .iter()
+      .enumerate()
+      .map(|(k, b)| if k % 2 == 0 { *b as isize } else { -(*b as isize) })
+      .sum();
+    assert_eq!(alternating_sum, complex.euler_characteristic());
+
+    let reduced_h0 = complex.reduced_homology::<Boolean>(0);
+    assert_eq!(reduced_h0.betti_number, 1);
+    // The generator has an even number of vertices, so it lies in the kernel of the augmentation.
+    let support = reduced_h0.homology_generators[0].iter().filter(|c| **c == Boolean(true)).count();
+    assert_eq!(support % 2, 0);
+    for (k, betti_k) in betti.iter().enumerate().skip(1) {
+      assert_eq!(complex.reduced_homology::<Boolean>(k).betti_number, *betti_k);
+    }
+
+    // Contractible complexes have vanishing reduced homology.
+    let mut triangle = SimplicialComplex::new();
+    triangle.join_element(Simplex::new(2, vec![0, 1, 2]));
+    assert!((0..=2).all(|k| triangle.reduced_homology::<f64>(k).betti_number == 0));
+    assert_eq!(triangle.euler_characteristic(), 1);
+
+    let empty = SimplicialComplex::new();
+    assert!(empty.f_vector().is_empty());
+    assert_eq!(empty.euler_characteristic(), 0);
+    assert_eq!(empty.reduced_homology::<Boolean>(0).betti_number, 0);
+  }
+
+  #[test]
+  fn test_homology_generator_chains() {
+    // A hollow tetrahedron with a triangular loop attached at vertex 3.
+    let mut complex = SimplicialComplex::new();
+    for face in [[0, 1, 2], [0, 1, 3], [0, 2, 3], [1, 2, 3]] {
+      complex.join_element(Simplex::new(2, face.to_vec()));
+    }
+    for edge in [[3, 4], [4, 5], [3, 5]] {
+      complex.join_element(Simplex::new(1, edge.to_vec()));
+    }
+
+    let basis = complex.basis(1);
+    assert_eq!(basis.len(), 9);
+    assert!(basis.windows(2).all(|w| w[0] < w[1]));
+
+    // Over the rationals the loop is a genuine cycle with signed coefficients.
+    let h1 = complex.homology::<f64>(1);
+    let loops = complex.homology_generator_chains(&h1);
+    assert_eq!(loops.len(), 1);
+    let mut edges: Vec<Vec<usize>> = loops[0].items.iter().map(|e| e.vertices().to_vec()).collect();
+    edges.sort();
+    assert_eq!(edges, vec![vec![3, 4], vec![3, 5], vec![4, 5]]);
+    assert!(loops[0].items.iter().all(|edge| complex.find_equivalent_element(edge).is_some()));
+    let boundary = loops[0].boundary();
+    assert!(boundary.coefficients.iter().all(|c| c.abs() < 1e-10));
+
+    // The void is enclosed by all four triangles.
+    let h2 = complex.homology::<Boolean>(2);
+    let voids = complex.homology_generator_chains(&h2);
+    assert_eq!(voids.len(), 1);
+    assert_eq!(voids[0].items.len(), 4);
+    assert!(voids[0].boundary().items.is_empty());
+  }
+
+  #[test]
+  fn test_star_link_and_closure() {
+    // Octahedron boundary: every vertex link is a 4-cycle and every edge link is two points.
+    let mut octahedron = SimplicialComplex::new();
+    for (a, b, c) in
+      [(0, 2, 4), (0, 2, 5), (0, 3, 4), (0, 3, 5), (1, 2, 4), (1, 2, 5), (1, 3, 4), (1, 3, 5)]
+    {
+      octahedron.join_element(Simplex::new(2, vec![a, b, c]));
+    }
+    let vertex = Simplex::new(0, vec![0]);
+    let edge = Simplex::new(1, vec![0, 2]);
+
+    assert_eq!(octahedron.star(std::slice::from_ref(&vertex)).len(), 1 + 4 + 4);
+    let closed_star = octahedron.closed_star(std::slice::from_ref(&vertex));
+    assert_eq!(closed_star.f_vector(), vec![5, 8, 4]);
+    // The subcomplex keeps the IDs of the parent complex.
+    for element in closed_star.elements.values() {
+      assert!(octahedron.get_element(element.id().unwrap()).unwrap().same_content(element));
+    }
+
+    let vertex_link = octahedron.link(std::slice::from_ref(&vertex));
+    assert_eq!(vertex_link.f_vector(), vec![4, 4]);
+    let link_vertices: Vec<usize> = vertex_link.basis(0).iter().map(|v| v.vertices()[0]).collect();
+    assert_eq!(link_vertices, vec![2, 3, 4, 5]);
+    assert_eq!(vertex_link.homology::<Boolean>(1).betti_number, 1);
+
+    let edge_link = octahedron.link(std::slice::from_ref(&edge));
+    assert_eq!(edge_link.f_vector(), vec![2]);
+
+    // Closure of a set, and the link of a set of elements.
+    let closure = octahedron.closure(&[edge, Simplex::new(2, vec![1, 3, 5])]);
+    assert_eq!(closure.f_vector(), vec![5, 4, 1]);
+    let two_vertices = octahedron.link(&[vertex, Simplex::new(0, vec![1])]);
+    assert_eq!(two_vertices.f_vector(), vec![4, 4]);
+  }
+
+  #[test]
+  fn test_cubical_link() {
+    // The link of the center of a 2×2 block of squares is the outer cycle of eight edges.
+    let mut block = CubicalComplex::new();
+    for square in [[0, 1, 3, 4], [1, 2, 4, 5], [3, 4, 6, 7], [4, 5, 7, 8]] {
+      block.join_element(Cube::square(square));
+    }
+    let center = Cube::vertex(4);
+    let link = block.link(std::slice::from_ref(&center));
+    assert_eq!(link.f_vector(), vec![8, 8]);
+    assert_eq!(link.homology::<Boolean>(1).betti_number, 1);
+
+    // A corner is on the boundary, so its link is an arc.
+    let corner_link = block.link(&[Cube::vertex(0)]);
+    assert_eq!(corner_link.f_vector(), vec![3, 2]);
+    assert_eq!(corner_link.homology::<Boolean>(1).betti_number, 0);
+  }
+
+  #[test]
+  #[should_panic(expected = "elements must belong to the complex")]
+  fn test_star_requires_elements_of_complex() {
+    let mut complex = SimplicialComplex::new();
+    complex.join_element(Simplex::new(1, vec![0, 1]));
+    complex.star(&[Simplex::new(0, vec![2])]);
+  }
 }
@@ -399,6 +399,9 @@ impl Simplex {
 }
 
 impl ComplexElement for Simplex {
+  /// The dimension together with the vertex list.
+  type Content = (usize, Vec<usize>);
+
   fn dimension(&self) -> usize { self.dimension }
 
   /// Computes all $(k-1)$-dimensional faces of this $k$-simplex.
@@ -457,11 +460,65 @@ impl ComplexElement for Simplex {
 
   fn id(&self) -> Option<usize> { self.id }
 
+  fn content(&self) -> Self::Content { (self.dimension, self.vertices.clone()) }
+
   fn same_content(&self, other: &Self) -> bool { self.same_content(other) }
 
   fn with_id(&self, new_id: usize) -> Self { self.clone().with_id(new_id) }
 }
 
+impl Complex<Simplex> {
+  /// Returns the h-vector of the simplicial complex.
+  ///
+  /// For a complex of dimension d - 1 with f-vector (f₋₁ = 1, f₀, ..., f_{d-1}), the h-vector
+  /// (h₀, ..., h_d) is defined by
+  ///
+  /// ```text
+  /// hₖ = Σᵢ₌₀ᵏ (-1)ᵏ⁻ⁱ C(d - i, k - i) fᵢ₋₁
+  /// ```
+  ///
+  /// It carries the same information as the f-vector in a form better suited to combinatorics:
+  /// for the boundary of a simplicial polytope it is symmetric (the Dehn–Sommerville relations)
+  /// and for Cohen–Macaulay complexes all entries are non-negative. The sum of the entries is
+  /// always f_{d-1}, the number of facets of top dimension, and h_d = (-1)^{d-1} χ̃.
+  ///
+  /// An empty complex has h-vector `[1]`, corresponding to the empty face alone.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use cova_space::complexes::{Simplex, SimplicialComplex};
+  ///
+  /// // The boundary of the tetrahedron has f-vector (4, 6, 4) and h-vector (1, 1, 1, 1).
+  /// let mut sphere = SimplicialComplex::new();
+  /// for face in [[0, 1, 2], [0, 1, 3], [0, 2, 3], [1, 2, 3]] {
+  ///   sphere.join_element(Simplex::new(2, face.to_vec()));
+  /// }
+  /// assert_eq!(sphere.h_vector(), vec![1, 1, 1, 1]);
+  /// ```
+  pub fn h_vector(&self) -> Vec<i64> {
+    let f_vector: Vec<i64> =
+      std::iter::once(1).chain(self.f_vector().into_iter().map(|f| f as i64)).collect();
+    let d = f_vector.len() - 1;
+
+    (0..=d)
+      .map(|k| {
+        (0..=k)
+          .map(|i| {
+            let sign = if (k - i) % 2 == 0 { 1 } else { -1 };
+            sign * binomial(d - i, k - i) * f_vector[i]
+          })
+          .sum()
+      })
+      .collect()
+  }
+}
+
+/// Computes the binomial coefficient C(n, k) for k ≤ n.
+fn binomial(n: usize, k: usize) -> i64 {
+  (0..k).fold(1, |acc, i| acc * (n - i) as i64 / (i + 1) as i64)
+}
+
 #[cfg(test)]
 mod tests {
   use std::fmt::Debug;
@@ -835,4 +892,30 @@ mod tests {
 
     println!("✓ Simplicial chain complex property verified for complex structures");
   }
+
+  #[test]
+  fn test_h_vector() {
+    let mut triangle = SimplicialComplex::new();
+    triangle.join_element(Simplex::new(2, vec![0, 1, 2]));
+    assert_eq!(triangle.h_vector(), vec![1, 0, 0, 0]);
+
+    // A path with three edges: f = (1, 4, 3).
+    let mut path = SimplicialComplex::new();
+    for edge in [[0, 1], [1, 2], [2, 3]] {
+      path.join_element(Simplex::new(1, edge.to_vec()));
+    }
+    assert_eq!(path.h_vector(), vec![1, 2, 0]);
+
+    // The octahedron boundary is a simplicial polytope: its h-vector is symmetric.
+    let mut octahedron = SimplicialComplex::new();
+    for (a, b, c) in
+      [(0, 2, 4), (0, 2, 5), (0, 3, 4), (0, 3, 5), (1, 2, 4), (1, 2, 5), (1, 3, 4), (1, 3, 5)]
+    {
+      octahedron.join_element(Simplex::new(2, vec![a, b, c]));
+    }
+    assert_eq!(octahedron.f_vector(), vec![6, 12, 8]);
+    assert_eq!(octahedron.h_vector(), vec![1, 3, 3, 1]);
+
+    assert_eq!(SimplicialComplex::new().h_vector(), vec![1]);
+  }
 }
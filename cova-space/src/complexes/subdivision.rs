@@ -0,0 +1,350 @@
+//! # Subdivisions of Complexes
+//!
+//! This module refines complexes into finer complexes with the same underlying space:
+//! barycentric subdivision of a [`SimplicialComplex`] and the analogous subdivision of a
+//! [`CubicalComplex`] that splits every $k$-cube into $2^k$ smaller cubes. Both return a
+//! [`Subdivision`], which records for every new cell the original cell carrying it.
+//!
+//! ## Mathematical Background
+//!
+//! Both subdivisions place a new vertex $\hat\sigma$ at the center of every cell $\sigma$ of the
+//! original complex $K$ and are described entirely by the face poset of $K$:
+//!
+//! - **Barycentric subdivision** $\operatorname{sd} K$ has a $k$-simplex $[\hat\sigma_0, \ldots,
+//!   \hat\sigma_k]$ for every chain $\sigma_0 < \sigma_1 < \cdots < \sigma_k$ of faces in $K$.
+//! - **Cubical subdivision** has an $m$-cube for every interval $[\tau, \sigma]$ of the face poset
+//!   with $\dim \sigma - \dim \tau = m$; its vertices are the centers of all $\rho$ with $\tau \le
+//!   \rho \le \sigma$. A $k$-cube is thereby cut into $2^k$ cubes, one per vertex.
+//!
+//! The **carrier** of a new cell is the smallest original cell containing it: the top
+//! $\sigma_k$ of its chain, or the top $\sigma$ of its interval. The carrier map is what relates
+//! the two complexes, e.g. to pull data on $K$ back to its refinement or to build a simplicial
+//! approximation of the identity $\operatorname{sd} K \to K$ (see
+//! [`Subdivision::simplicial_approximation`]).
+//!
+//! ## Vertex Labels
+//!
+//! The vertex $\hat\sigma$ is labelled by the ID of $\sigma$ in the original complex, so labels
+//! of the new complex are element IDs of the old one. [`Subdivision::vertex_carrier`] maps a
+//! label back to its cell.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use cova_space::complexes::{Simplex, SimplicialComplex};
+//!
+//! let mut triangle = SimplicialComplex::new();
+//! triangle.join_element(Simplex::new(2, vec![0, 1, 2]));
+//!
+//! let subdivision = triangle.barycentric_subdivision();
+//! assert_eq!(subdivision.complex.f_vector(), vec![7, 12, 6]);
+//!
+//! // Every new triangle is carried by the original triangle.
+//! for small in subdivision.complex.elements_of_dimension(2) {
+//!   assert_eq!(subdivision.carrier(&small).vertices(), &[0, 1, 2]);
+//! }
+//! ```
+
+use std::collections::HashMap;
+
+use super::*;
+
+/// A subdivision of a complex together with its carrier map.
+///
+/// # Type Parameters
+///
+/// * `T`: The element type, must implement [`ComplexElement`]
+#[derive(Debug, Clone)]
+pub struct Subdivision<T: ComplexElement> {
+  /// The subdivided complex. Its vertex labels are element IDs of the original complex.
+  pub complex:     Complex<T>,
+  /// The original cell at whose center each new vertex sits, keyed by vertex label.
+  vertex_carriers: HashMap<usize, T>,
+}
+
+impl<T: ComplexElement> Subdivision<T> {
+  /// Returns the original cell whose center is the vertex with the given label, if any.
+  pub fn vertex_carrier(&self, label: usize) -> Option<&T> { self.vertex_carriers.get(&label) }
+
+  /// Returns the original cell of highest dimension among the carriers of `labels`.
+  fn carrier_of_vertices(&self, labels: &[usize]) -> T {
+    labels
+      .iter()
+      .map(|label| {
+        self.vertex_carrier(*label).unwrap_or_else(|| panic!("{label} is not a subdivision vertex"))
+      })
+      .max_by_key(|cell| cell.dimension())
+      .expect("cells have at least one vertex")
+      .clone()
+  }
+}
+
+impl Subdivision<Simplex> {
+  /// Returns the carrier of `simplex`: the smallest simplex of the original complex containing
+  /// it.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `simplex` has a vertex label that is not a vertex of the subdivision.
+  pub fn carrier(&self, simplex: &Simplex) -> Simplex {
+    self.carrier_of_vertices(simplex.vertices())
+  }
+
+  /// Returns the simplicial approximation $\operatorname{sd} K \to K$ of the identity that sends
+  /// each new vertex $\hat\sigma$ to the largest vertex label of $\sigma$.
+  ///
+  /// A chain $\sigma_0 < \cdots < \sigma_k$ is sent into $\sigma_k$, so this is a simplicial map.
+  /// It induces isomorphisms on homology, which is how features found on a refinement are
+  /// identified with features of the original complex.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `original` is not the complex this subdivision was computed from.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use cova_algebra::algebras::boolean::Boolean;
+  /// use cova_space::complexes::{Simplex, SimplicialComplex};
+  ///
+  /// let mut circle = SimplicialComplex::new();
+  /// for edge in [[0, 1], [1, 2], [0, 2]] {
+  ///   circle.join_element(Simplex::new(1, edge.to_vec()));
+  /// }
+  /// let subdivision = circle.barycentric_subdivision();
+  ///
+  /// let approximation = subdivision.simplicial_approximation(&circle);
+  /// assert_eq!(approximation.induced_homology_map::<Boolean>(1).rank(), 1);
+  /// ```
+  pub fn simplicial_approximation<'a>(
+    &'a self,
+    original: &'a SimplicialComplex,
+  ) -> SimplicialMap<'a> {
+    let vertex_map = self
+      .vertex_carriers
+      .iter()
+      .map(|(&label, cell)| {
+        assert!(
+          original.get_element(label).is_some_and(|element| element.same_content(cell)),
+          "the subdivision was not computed from this complex"
+        );
+        (label, *cell.vertices().last().expect("simplices have at least one vertex"))
+      })
+      .collect();
+    SimplicialMap::new(&self.complex, original, vertex_map)
+  }
+}
+
+impl Subdivision<Cube> {
+  /// Returns the carrier of `cube`: the smallest cube of the original complex containing it.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `cube` has a vertex label that is not a vertex of the subdivision.
+  pub fn carrier(&self, cube: &Cube) -> Cube { self.carrier_of_vertices(cube.vertices()) }
+}
+
+impl Complex<Simplex> {
+  /// Computes the barycentric subdivision of the complex.
+  ///
+  /// See the [module documentation](self) for the construction and the labelling of vertices.
+  /// Only the flags of faces below maximal simplices are enumerated, so a $d$-simplex produces
+  /// $(d+1)!$ simplices of top dimension.
+  pub fn barycentric_subdivision(&self) -> Subdivision<Simplex> {
+    let mut complex = Self::new();
+    for element in self.elements.values() {
+      if !self.cofaces(element).is_empty() {
+        continue;
+      }
+      for flag in self.maximal_flags(element) {
+        complex.join_element(Simplex::from_vertices(flag));
+      }
+    }
+    Subdivision { complex, vertex_carriers: self.vertex_carriers() }
+  }
+
+  /// Returns the IDs of all maximal chains of faces ending in `simplex`, from a vertex upwards.
+  fn maximal_flags(&self, simplex: &Simplex) -> Vec<Vec<usize>> {
+    let id = simplex.id().expect("elements of a complex have IDs");
+    if simplex.dimension() == 0 {
+      return vec![vec![id]];
+    }
+    self
+      .faces(simplex)
+      .iter()
+      .flat_map(|face| self.maximal_flags(face))
+      .map(|mut flag| {
+        flag.push(id);
+        flag
+      })
+      .collect()
+  }
+}
+
+impl Complex<Cube> {
+  /// Computes the cubical subdivision of the complex, cutting every $k$-cube into $2^k$ cubes.
+  ///
+  /// See the [module documentation](self) for the construction and the labelling of vertices.
+  /// The cube of the interval $[\tau, \sigma]$ takes its coordinate axes from the coordinates of
+  /// $\sigma$ that are fixed on $\tau$, in their original order.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use cova_space::complexes::{Cube, CubicalComplex};
+  ///
+  /// let mut square = CubicalComplex::new();
+  /// square.join_element(Cube::square([0, 1, 2, 3]));
+  ///
+  /// let subdivision = square.cubical_subdivision();
+  /// assert_eq!(subdivision.complex.f_vector(), vec![9, 12, 4]);
+  /// ```
+  pub fn cubical_subdivision(&self) -> Subdivision<Cube> {
+    let ids_by_vertex_set: HashMap<(usize, Vec<usize>), usize> = self
+      .elements
+      .iter()
+      .map(|(&id, cube)| {
+        let mut vertices = cube.vertices().to_vec();
+        vertices.sort_unstable();
+        ((cube.dimension(), vertices), id)
+      })
+      .collect();
+
+    let mut complex = Self::new();
+    for cube in self.elements.values() {
+      if !self.cofaces(cube).is_empty() {
+        continue;
+      }
+      // One top-dimensional cube [v, σ] per corner v of σ.
+      for corner in 0..cube.vertices().len() {
+        complex.join_element(Self::interval_cube(cube, corner, &ids_by_vertex_set));
+      }
+    }
+    Subdivision { complex, vertex_carriers: self.vertex_carriers() }
+  }
+
+  /// Returns the cube of the subdivision spanned by the interval from the vertex at index
+  /// `corner` of `cube` up to `cube` itself.
+  fn interval_cube(
+    cube: &Cube,
+    corner: usize,
+    ids_by_vertex_set: &HashMap<(usize, Vec<usize>), usize>,
+  ) -> Cube {
+    let k = cube.dimension();
+    // Freeing the coordinates in `free` (a bitmask over the axes of `cube`) gives the face of
+    // `cube` through `corner` of dimension `free.count_ones()`.
+    let face_id = |free: usize| {
+      let mut vertices: Vec<usize> = (0..cube.vertices().len())
+        .filter(|index| (index ^ corner) & !free == 0)
+        .map(|index| cube.vertices()[index])
+        .collect();
+      vertices.sort_unstable();
+      ids_by_vertex_set[&(free.count_ones() as usize, vertices)]
+    };
+    Cube::new(k, (0..1 << k).map(face_id).collect())
+  }
+}
+
+impl<T: ComplexElement> Complex<T> {
+  /// Returns the original cell for every new vertex label, i.e. every element keyed by its ID.
+  fn vertex_carriers(&self) -> HashMap<usize, T> {
+    self.elements.iter().map(|(&id, element)| (id, element.clone())).collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use cova_algebra::algebras::boolean::Boolean;
+
+  use super::*;
+
+  #[test]
+  fn test_barycentric_subdivision_preserves_homology() {
+    let mut sphere = SimplicialComplex::new();
+    for face in [[0, 1, 2], [0, 1, 3], [0, 2, 3], [1, 2, 3]] {
+      sphere.join_element(Simplex::new(2, face.to_vec()));
+    }
+    let subdivision = sphere.barycentric_subdivision();
+
+    // New vertices are the 14 cells; each of the 4 triangles splits into 6.
+    assert_eq!(subdivision.complex.f_vector(), vec![14, 36, 24]);
+    assert_eq!(subdivision.complex.betti_numbers::<Boolean>(), sphere.betti_numbers::<Boolean>());
+    assert_eq!(subdivision.complex.euler_characteristic(), sphere.euler_characteristic());
+
+    let approximation = subdivision.simplicial_approximation(&sphere);
+    assert_eq!(approximation.induced_homology_map::<Boolean>(2).rank(), 1);
+  }
+
+  #[test]
+  fn test_barycentric_subdivision_carriers() {
+    let mut complex = SimplicialComplex::new();
+    complex.join_element(Simplex::new(2, vec![0, 1, 2]));
+    complex.join_element(Simplex::new(1, vec![2, 3]));
+    let subdivision = complex.barycentric_subdivision();
+
+    // Original vertices keep a vertex of the subdivision carried by themselves.
+    for vertex in complex.elements_of_dimension(0) {
+      let label = vertex.id().unwrap();
+      assert!(subdivision.vertex_carrier(label).unwrap().same_content(&vertex));
+    }
+
+    // The edge {2, 3} is split in two, and both halves are carried by it.
+    let halves: Vec<Simplex> = subdivision
+      .complex
+      .elements_of_dimension(1)
+      .into_iter()
+      .filter(|edge| subdivision.carrier(edge).vertices() == [2, 3])
+      .collect();
+    assert_eq!(halves.len(), 2);
+
+    // Carriers contain the carriers of all faces.
+    for element in subdivision.complex.elements.values() {
+      let carrier = subdivision.carrier(element);
+      for face in subdivision.complex.faces(element) {
+        let face_carrier = subdivision.carrier(&face);
+        assert!(face_carrier.vertices().iter().all(|v| carrier.vertices().contains(v)));
+      }
+    }
+  }
+
+  #[test]
+  fn test_cubical_subdivision() {
+    // An annulus: a 3×3 block of squares with the middle one removed.
+    let vertex = |i: usize, j: usize| 4 * j + i;
+    let mut annulus = CubicalComplex::new();
+    for j in 0..3 {
+      for i in 0..3 {
+        if (i, j) != (1, 1) {
+          annulus.join_element(Cube::square([
+            vertex(i, j),
+            vertex(i + 1, j),
+            vertex(i, j + 1),
+            vertex(i + 1, j + 1),
+          ]));
+        }
+      }
+    }
+    let subdivision = annulus.cubical_subdivision();
+
+    let f = annulus.f_vector();
+    assert_eq!(subdivision.complex.f_vector(), vec![
+      f[0] + f[1] + f[2],
+      2 * f[1] + 4 * f[2],
+      4 * f[2]
+    ]);
+    assert_eq!(subdivision.complex.betti_numbers::<Boolean>(), vec![1, 1, 0]);
+
+    for square in subdivision.complex.elements_of_dimension(2) {
+      assert_eq!(subdivision.carrier(&square).dimension(), 2);
+    }
+  }
+
+  #[test]
+  fn test_cubical_subdivision_of_cube() {
+    let mut cube = CubicalComplex::new();
+    cube.join_element(Cube::new(3, (0..8).collect()));
+    let subdivision = cube.cubical_subdivision();
+    assert_eq!(subdivision.complex.f_vector(), vec![27, 54, 36, 8]);
+    assert_eq!(subdivision.complex.betti_numbers::<Boolean>(), vec![1, 0, 0, 0]);
+  }
+}
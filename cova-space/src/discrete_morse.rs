@@ -0,0 +1,655 @@
+//! # Discrete Morse Theory
+//!
+//! This module computes discrete gradient vector fields (acyclic matchings) on cell complexes
+//! and the associated **Morse complex**, a much smaller chain complex built only from the
+//! critical cells whose homology agrees with that of the original complex.
+//!
+//! ## Mathematical Background
+//!
+//! A **discrete vector field** $V$ on a complex $K$ is a set of pairs $(\tau, \sigma)$ where
+//! $\tau$ is a face of $\sigma$ with $\dim \sigma = \dim \tau + 1$ and $[\sigma : \tau] = \pm 1$,
+//! such that every cell lies in at most one pair. Cells that are not paired are **critical**.
+//!
+//! A **V-path** of dimension $k$ is a sequence
+//! $$ \tau_0, \sigma_0, \tau_1, \sigma_1, \ldots, \sigma_{r-1}, \tau_r $$
+//! of $k$-cells $\tau_i$ and $(k+1)$-cells $\sigma_i$ with $(\tau_i, \sigma_i) \in V$ and
+//! $\tau_{i+1} \neq \tau_i$ a face of $\sigma_i$. The field is a **discrete gradient** when it
+//! admits no closed V-paths; the matching is then called **acyclic**.
+//!
+//! Forman's fundamental theorem states that a discrete gradient with $m_k$ critical $k$-cells
+//! yields a chain complex $M_\bullet$ with $M_k$ spanned by the critical $k$-cells and
+//! $H_k(M_\bullet) \cong H_k(K)$. Its boundary sums over all V-paths from the faces of a critical
+//! $(k+1)$-cell $\sigma$ to critical $k$-cells $\tau$:
+//! $$ \partial^M \sigma = \sum_{\tau \text{ critical}} \Big( \sum_{\gamma : \partial \sigma
+//! \rightsquigarrow \tau} w(\gamma) \Big) \tau, $$
+//! where each step $\tau_i \to \sigma_i \to \tau_{i+1}$ contributes the weight $-[\sigma_i :
+//! \tau_{i+1}] / [\sigma_i : \tau_i]$. In particular the weak Morse inequalities $m_k \geq
+//! \beta_k$ hold.
+//!
+//! ## Constructing Gradients
+//!
+//! - [`DiscreteGradient::from_coreductions`] builds a gradient greedily by **coreductions**: a cell
+//!   with exactly one remaining face is paired with that face, and when no such cell exists a
+//!   remaining cell of lowest dimension is declared critical. This typically leaves very few
+//!   critical cells and is the method of choice for speeding up homology computations.
+//! - [`DiscreteGradient::lower_star`] builds the gradient of Robins, Wood and Sheppard from a
+//!   scalar function on the vertices. Every pair stays within the lower star of a single vertex, so
+//!   the critical cells reflect the critical points of the function, which makes it suitable for
+//!   analysing scalar fields on meshes.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use cova_algebra::algebras::boolean::Boolean;
+//! use cova_space::{
+//!   complexes::{Simplex, SimplicialComplex},
+//!   discrete_morse::DiscreteGradient,
+//! };
+//!
+//! // The boundary of a tetrahedron is a sphere.
+//! let mut sphere = SimplicialComplex::new();
+//! for face in [[0, 1, 2], [0, 1, 3], [0, 2, 3], [1, 2, 3]] {
+//!   sphere.join_element(Simplex::new(2, face.to_vec()));
+//! }
+//!
+//! let gradient = DiscreteGradient::from_coreductions(&sphere);
+//! assert_eq!(gradient.morse_numbers(), vec![1, 0, 1]);
+//!
+//! let morse_complex = gradient.morse_complex::<Boolean>();
+//! assert_eq!(morse_complex.homology(2).betti_number, 1);
+//! ```
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use cova_algebra::{
+  rings::Field,
+  tensors::{
+    DMatrix,
+    sparse::{SparseMatrix, kernel_modulo_image},
+  },
+};
+
+use crate::{
+  complexes::{Complex, ComplexElement, incidence_coefficient},
+  homology::Homology,
+  set::Poset,
+};
+
+/// A discrete gradient vector field on a [`Complex`].
+///
+/// The gradient borrows the complex it was computed on and stores its pairs by element ID. Every
+/// element is either the face of exactly one pair, the coface of exactly one pair, or critical.
+#[derive(Debug, Clone)]
+pub struct DiscreteGradient<'a, T: ComplexElement> {
+  complex: &'a Complex<T>,
+  /// Maps the ID of the face of each pair to the ID of its coface.
+  up:      HashMap<usize, usize>,
+  /// Maps the ID of the coface of each pair to the ID of its face.
+  down:    HashMap<usize, usize>,
+}
+
+/// The Morse chain complex of a [`DiscreteGradient`] over a field `F`.
+///
+/// Its $k$-chains are spanned by the critical $k$-cells, sorted by their [`Ord`] implementation,
+/// and its homology is isomorphic to that of the underlying complex.
+#[derive(Debug, Clone)]
+pub struct MorseComplex<T, F> {
+  critical_cells: Vec<Vec<T>>,
+  /// `boundaries[k]` is $\partial^M_k : M_k \to M_{k-1}$, with $\partial^M_0$ the zero map.
+  boundaries:     Vec<SparseMatrix<F>>,
+}
+
+/// Face incidences of every element of a complex, keyed by element ID.
+struct Incidences {
+  /// Immediate faces of each element together with their incidence numbers.
+  faces:   HashMap<usize, Vec<(usize, i32)>>,
+  /// Immediate cofaces of each element.
+  cofaces: HashMap<usize, Vec<usize>>,
+}
+
+impl Incidences {
+  fn new<T: ComplexElement>(complex: &Complex<T>) -> Self {
+    let mut faces = HashMap::new();
+    let mut cofaces = HashMap::new();
+    for (&id, element) in &complex.elements {
+      let oriented_faces = element.boundary_with_orientations();
+      let incidences = complex
+        .faces(element)
+        .iter()
+        .map(|face| {
+          let incidence = oriented_faces
+            .iter()
+            .filter(|(boundary_face, _)| boundary_face.same_content(face))
+            .map(|(_, orientation)| orientation)
+            .sum();
+          (face.id().expect("complex elements have IDs"), incidence)
+        })
+        .collect();
+      faces.insert(id, incidences);
+      cofaces.insert(id, complex.cofaces(element).iter().filter_map(T::id).collect());
+    }
+    Self { faces, cofaces }
+  }
+
+  /// Returns the incidence number of the face `tau` in the boundary of `sigma`.
+  fn incidence(&self, sigma: usize, tau: usize) -> i32 {
+    self.faces[&sigma].iter().find(|(face, _)| *face == tau).map_or(0, |(_, incidence)| *incidence)
+  }
+}
+
+impl<'a, T: ComplexElement> DiscreteGradient<'a, T> {
+  /// Builds an acyclic matching on `complex` by greedy coreductions.
+  ///
+  /// Cells are removed one by one. Whenever a cell has exactly one remaining face, and that face
+  /// is regular, the two are paired and removed together; otherwise the remaining cell of lowest
+  /// dimension (and lowest ID among those) is declared critical and removed. Since every other
+  /// face of a paired cell was removed before the pair, the removal order strictly decreases
+  /// along V-paths and the matching is acyclic.
+  pub fn from_coreductions(complex: &'a Complex<T>) -> Self {
+    let incidences = Incidences::new(complex);
+    let mut order: Vec<usize> = complex.elements.keys().copied().collect();
+    order.sort_unstable_by_key(|id| (complex.elements[id].dimension(), *id));
+
+    let mut gradient = Self { complex, up: HashMap::new(), down: HashMap::new() };
+    let mut remaining: HashSet<usize> = order.iter().copied().collect();
+    let mut face_count: HashMap<usize, usize> =
+      incidences.faces.iter().map(|(&id, faces)| (id, faces.len())).collect();
+    let mut candidates = Vec::new();
+    let mut next_critical = order.into_iter();
+
+    loop {
+      while let Some(sigma) = candidates.pop() {
+        if !remaining.contains(&sigma) || face_count[&sigma] != 1 {
+          continue;
+        }
+        let (tau, incidence) = *incidences.faces[&sigma]
+          .iter()
+          .find(|(face, _)| remaining.contains(face))
+          .expect("a coreducible cell has exactly one remaining face");
+        if incidence.abs() != 1 {
+          continue;
+        }
+        gradient.pair(tau, sigma);
+        for cell in [tau, sigma] {
+          Self::remove_cell(cell, &incidences, &mut remaining, &mut face_count, &mut candidates);
+        }
+      }
+
+      let Some(critical) = next_critical.find(|id| remaining.contains(id)) else { break };
+      Self::remove_cell(critical, &incidences, &mut remaining, &mut face_count, &mut candidates);
+    }
+
+    gradient
+  }
+
+  /// Removes `cell` during coreduction and queues cofaces that become coreducible.
+  fn remove_cell(
+    cell: usize,
+    incidences: &Incidences,
+    remaining: &mut HashSet<usize>,
+    face_count: &mut HashMap<usize, usize>,
+    candidates: &mut Vec<usize>,
+  ) {
+    remaining.remove(&cell);
+    for coface in &incidences.cofaces[&cell] {
+      if !remaining.contains(coface) {
+        continue;
+      }
+      let count = face_count.get_mut(coface).expect("every element has a face count");
+      *count -= 1;
+      if *count == 1 {
+        candidates.push(*coface);
+      }
+    }
+  }
+
+  /// Builds the lower-star gradient of a scalar function on the vertices of `complex`.
+  ///
+  /// `value` is evaluated on every 0-dimensional element. Ties are broken by element ID, so the
+  /// function is effectively made injective, and every cell is assigned to the lower star of its
+  /// highest vertex. Within each lower star the algorithm of Robins, Wood and Sheppard pairs
+  /// cells in order of their sorted vertex values, so that a critical vertex is a local minimum
+  /// and, on a manifold, critical cells of higher dimension sit at saddles and maxima of the
+  /// function.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `value` returns NaN.
+  pub fn lower_star(complex: &'a Complex<T>, value: impl Fn(&T) -> f64) -> Self {
+    let incidences = Incidences::new(complex);
+
+    let mut vertices: Vec<(f64, usize)> = complex
+      .elements_of_dimension(0)
+      .iter()
+      .map(|vertex| {
+        let vertex_value = value(vertex);
+        assert!(!vertex_value.is_nan(), "vertex values must not be NaN");
+        (vertex_value, vertex.id().expect("complex elements have IDs"))
+      })
+      .collect();
+    vertices.sort_unstable_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+    let rank: HashMap<usize, usize> =
+      vertices.iter().enumerate().map(|(rank, &(_, id))| (id, rank)).collect();
+
+    // Each cell is keyed by the ranks of its vertices in decreasing order; the first entry
+    // identifies the lower star it belongs to.
+    let keys: HashMap<usize, Vec<usize>> = complex
+      .elements
+      .keys()
+      .map(|&id| {
+        let mut key: Vec<usize> = complex
+          .attachment_lattice
+          .downset(id)
+          .iter()
+          .filter_map(|cell| rank.get(cell).copied())
+          .collect();
+        key.sort_unstable_by(|a, b| b.cmp(a));
+        (id, key)
+      })
+      .collect();
+    let mut lower_stars: Vec<Vec<usize>> = vec![Vec::new(); vertices.len()];
+    for (&id, key) in &keys {
+      lower_stars[key[0]].push(id);
+    }
+
+    let mut gradient = Self { complex, up: HashMap::new(), down: HashMap::new() };
+    for (vertex_rank, lower_star) in lower_stars.into_iter().enumerate() {
+      let vertex = vertices[vertex_rank].1;
+      let in_star: HashSet<usize> = lower_star.iter().copied().collect();
+      let mut assigned: HashSet<usize> = HashSet::from([vertex]);
+      let queue_entry = |id: usize| (keys[&id].clone(), id);
+      let unassigned_faces = |id: usize, assigned: &HashSet<usize>| -> Vec<usize> {
+        incidences.faces[&id]
+          .iter()
+          .map(|&(face, _)| face)
+          .filter(|face| in_star.contains(face) && !assigned.contains(face))
+          .collect()
+      };
+
+      let mut edges: Vec<usize> =
+        lower_star.iter().copied().filter(|id| complex.elements[id].dimension() == 1).collect();
+      edges.sort_unstable_by_key(|&id| queue_entry(id));
+      let Some((&delta, other_edges)) = edges.split_first() else {
+        // Without edges in its lower star the vertex is a local minimum and the star is {vertex}.
+        continue;
+      };
+
+      gradient.pair(vertex, delta);
+      assigned.insert(delta);
+      let mut pq_one: BTreeSet<(Vec<usize>, usize)> =
+        other_edges.iter().map(|&id| queue_entry(id)).collect();
+      let mut pq_zero: BTreeSet<(Vec<usize>, usize)> = BTreeSet::new();
+      let push_coreducible =
+        |cell: usize, assigned: &HashSet<usize>, pq_zero: &mut BTreeSet<(Vec<usize>, usize)>| {
+          for &coface in &incidences.cofaces[&cell] {
+            if in_star.contains(&coface)
+              && !assigned.contains(&coface)
+              && unassigned_faces(coface, assigned).len() == 1
+            {
+              pq_zero.insert(queue_entry(coface));
+            }
+          }
+        };
+      push_coreducible(delta, &assigned, &mut pq_zero);
+
+      loop {
+        while let Some((_, alpha)) = pq_zero.pop_first() {
+          if assigned.contains(&alpha) {
+            continue;
+          }
+          match unassigned_faces(alpha, &assigned)[..] {
+            [] => {
+              pq_one.insert(queue_entry(alpha));
+            },
+            [face] if incidences.incidence(alpha, face).abs() == 1 => {
+              gradient.pair(face, alpha);
+              assigned.insert(face);
+              assigned.insert(alpha);
+              pq_one.remove(&queue_entry(face));
+              push_coreducible(alpha, &assigned, &mut pq_zero);
+              push_coreducible(face, &assigned, &mut pq_zero);
+            },
+            _ => {},
+          }
+        }
+
+        let next = pq_one.pop_first().map(|(_, id)| id).or_else(|| {
+          // Cells that were never queued (only possible for irregular incidences) stay critical.
+          lower_star
+            .iter()
+            .copied()
+            .filter(|id| !assigned.contains(id))
+            .min_by_key(|&id| queue_entry(id))
+        });
+        let Some(gamma) = next else { break };
+        if assigned.insert(gamma) {
+          push_coreducible(gamma, &assigned, &mut pq_zero);
+        }
+      }
+    }
+
+    gradient
+  }
+
+  fn pair(&mut self, face: usize, coface: usize) {
+    self.up.insert(face, coface);
+    self.down.insert(coface, face);
+  }
+
+  /// Returns the complex this gradient is defined on.
+  pub const fn complex(&self) -> &'a Complex<T> { self.complex }
+
+  /// Returns the gradient pairs $(\tau, \sigma)$, sorted by their face $\tau$.
+  pub fn pairs(&self) -> Vec<(T, T)> {
+    let mut pairs: Vec<(T, T)> = self
+      .up
+      .iter()
+      .map(|(face, coface)| {
+        (self.complex.elements[face].clone(), self.complex.elements[coface].clone())
+      })
+      .collect();
+    pairs.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+    pairs
+  }
+
+  /// Returns whether `cell` is critical, i.e. not part of any gradient pair.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `cell` is not an element of the underlying complex.
+  pub fn is_critical(&self, cell: &T) -> bool {
+    let id = self.id_of(cell);
+    !self.up.contains_key(&id) && !self.down.contains_key(&id)
+  }
+
+  /// Returns the cell `cell` is paired with, which is either a coface or a face of it, or `None`
+  /// if `cell` is critical.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `cell` is not an element of the underlying complex.
+  pub fn partner(&self, cell: &T) -> Option<T> {
+    let id = self.id_of(cell);
+    self
+      .up
+      .get(&id)
+      .or_else(|| self.down.get(&id))
+      .map(|partner| self.complex.elements[partner].clone())
+  }
+
+  /// Returns the critical cells of dimension `k`, sorted by their [`Ord`] implementation.
+  pub fn critical_cells(&self, k: usize) -> Vec<T> {
+    let mut cells = self.complex.elements_of_dimension(k);
+    cells.retain(|cell| self.is_critical(cell));
+    cells.sort_unstable();
+    cells
+  }
+
+  /// Returns the Morse numbers $m_k$, the number of critical cells in each dimension from 0 up to
+  /// the dimension of the complex.
+  pub fn morse_numbers(&self) -> Vec<usize> {
+    (0..=self.complex.max_dimension()).map(|k| self.critical_cells(k).len()).collect()
+  }
+
+  /// Builds the Morse chain complex of this gradient over the field `F`.
+  ///
+  /// The boundary of each critical $(k+1)$-cell is obtained by flowing its ordinary boundary
+  /// along the gradient: every $k$-cell that is the face of a pair is repeatedly replaced by the
+  /// remaining faces of its partner, cells that are the coface of a pair are dropped, and the
+  /// flow stabilises on critical cells because the matching is acyclic.
+  pub fn morse_complex<F: Field + Copy>(&self) -> MorseComplex<T, F> {
+    let incidences = Incidences::new(self.complex);
+    let critical_cells: Vec<Vec<T>> =
+      (0..=self.complex.max_dimension()).map(|k| self.critical_cells(k)).collect();
+
+    let mut boundaries = Vec::with_capacity(critical_cells.len());
+    boundaries.push(SparseMatrix::zeros(0, critical_cells.first().map_or(0, Vec::len)));
+    for k in 1..critical_cells.len() {
+      let row_index: HashMap<usize, usize> = critical_cells[k - 1]
+        .iter()
+        .enumerate()
+        .map(|(row, cell)| (cell.id().expect("complex elements have IDs"), row))
+        .collect();
+
+      let columns = critical_cells[k]
+        .iter()
+        .map(|cell| {
+          let sigma = cell.id().expect("complex elements have IDs");
+          let mut chain: HashMap<usize, F> = HashMap::new();
+          for &(face, incidence) in &incidences.faces[&sigma] {
+            chain.insert(face, incidence_coefficient::<F>(incidence));
+          }
+
+          while let Some(tau) = chain.keys().copied().find(|tau| self.up.contains_key(tau)) {
+            let coefficient = chain.remove(&tau).expect("the face was found in the chain");
+            let partner = self.up[&tau];
+            let factor = -(coefficient
+              * incidence_coefficient::<F>(incidences.incidence(partner, tau))
+                .multiplicative_inverse());
+            for &(face, incidence) in &incidences.faces[&partner] {
+              if face == tau {
+                continue;
+              }
+              let entry = chain.entry(face).or_insert_with(F::zero);
+              *entry += factor * incidence_coefficient::<F>(incidence);
+              if entry.is_zero() {
+                chain.remove(&face);
+              }
+            }
+          }
+
+          let mut column: Vec<(usize, F)> = chain
+            .into_iter()
+            .filter_map(|(face, coefficient)| row_index.get(&face).map(|&row| (row, coefficient)))
+            .collect();
+          column.sort_unstable_by_key(|&(row, _)| row);
+          column
+        })
+        .collect();
+      boundaries.push(SparseMatrix::from_columns(critical_cells[k - 1].len(), columns));
+    }
+
+    MorseComplex { critical_cells, boundaries }
+  }
+
+  fn id_of(&self, cell: &T) -> usize {
+    self
+      .complex
+      .find_equivalent_element(cell)
+      .and_then(|element| element.id())
+      .expect("cell must belong to the complex")
+  }
+}
+
+impl<T: ComplexElement, F: Field + Copy> MorseComplex<T, F> {
+  /// Returns the critical cells of dimension `k` that form the basis of $M_k$.
+  pub fn critical_cells(&self, k: usize) -> &[T] {
+    self.critical_cells.get(k).map_or(&[], Vec::as_slice)
+  }
+
+  /// Returns the Morse boundary matrix $\partial^M_k : M_k \to M_{k-1}$ as a dense matrix.
+  ///
+  /// Rows and columns are indexed by [`MorseComplex::critical_cells`] of dimensions $k-1$ and
+  /// $k$. For $k = 0$ the matrix has no rows.
+  pub fn boundary_matrix(&self, k: usize) -> DMatrix<F> {
+    self.sparse_boundary_matrix(k).to_dense()
+  }
+
+  /// Computes $H_k$ of the Morse complex, which is isomorphic to $H_k$ of the underlying complex.
+  ///
+  /// Generators are coefficient vectors in the basis of critical $k$-cells.
+  pub fn homology(&self, k: usize) -> Homology<F> {
+    if self.critical_cells(k).is_empty() {
+      return Homology::trivial(k);
+    }
+    let generators =
+      kernel_modulo_image(&self.sparse_boundary_matrix(k), &self.sparse_boundary_matrix(k + 1));
+    Homology {
+      dimension:           k,
+      betti_number:        generators.len(),
+      homology_generators: generators,
+    }
+  }
+
+  fn sparse_boundary_matrix(&self, k: usize) -> SparseMatrix<F> {
+    self
+      .boundaries
+      .get(k)
+      .cloned()
+      .unwrap_or_else(|| SparseMatrix::zeros(self.critical_cells(k.saturating_sub(1)).len(), 0))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use cova_algebra::{algebras::boolean::Boolean, modular, prime_field};
+
+  use super::*;
+  use crate::complexes::{Cube, CubicalComplex, Simplex, SimplicialComplex};
+
+  modular!(Mod7, u32, 7);
+  prime_field!(Mod7);
+
+  fn assert_same_homology<T: ComplexElement>(gradient: &DiscreteGradient<'_, T>) {
+    let complex = gradient.complex();
+    let boolean = gradient.morse_complex::<Boolean>();
+    let mod7 = gradient.morse_complex::<Mod7>();
+    for k in 0..=complex.max_dimension() + 1 {
+      assert_eq!(boolean.homology(k).betti_number, complex.homology::<Boolean>(k).betti_number);
+      assert_eq!(mod7.homology(k).betti_number, complex.homology::<Mod7>(k).betti_number);
+    }
+    for k in 1..=complex.max_dimension() {
+      let product = mod7.boundary_matrix(k) * mod7.boundary_matrix(k + 1);
+      assert!(product.iter().all(|entry| *entry == Mod7::new(0)));
+    }
+  }
+
+  fn assert_valid_matching<T: ComplexElement>(gradient: &DiscreteGradient<'_, T>) {
+    let complex = gradient.complex();
+    let mut seen = HashSet::new();
+    for (face, coface) in gradient.pairs() {
+      assert_eq!(coface.dimension(), face.dimension() + 1);
+      assert!(complex.faces(&coface).contains(&face));
+      assert!(seen.insert(face.id()) && seen.insert(coface.id()));
+    }
+    let critical: usize = gradient.morse_numbers().iter().sum();
+    assert_eq!(critical + seen.len(), complex.elements.len());
+  }
+
+  fn torus() -> SimplicialComplex {
+    // The standard 9-vertex triangulation of the torus on a 3×3 grid.
+    let vertex = |i: usize, j: usize| 3 * (j % 3) + (i % 3);
+    let mut torus = SimplicialComplex::new();
+    for j in 0..3 {
+      for i in 0..3 {
+        torus.join_element(Simplex::new(2, vec![
+          vertex(i, j),
+          vertex(i + 1, j),
+          vertex(i + 1, j + 1),
+        ]));
+        torus.join_element(Simplex::new(2, vec![
+          vertex(i, j),
+          vertex(i, j + 1),
+          vertex(i + 1, j + 1),
+        ]));
+      }
+    }
+    torus
+  }
+
+  #[test]
+  fn test_coreduction_gradient() {
+    let mut simplex = SimplicialComplex::new();
+    simplex.join_element(Simplex::new(3, vec![0, 1, 2, 3]));
+    let gradient = DiscreteGradient::from_coreductions(&simplex);
+    assert_valid_matching(&gradient);
+    assert_eq!(gradient.morse_numbers(), vec![1, 0, 0, 0]);
+
+    let torus = torus();
+    let gradient = DiscreteGradient::from_coreductions(&torus);
+    assert_valid_matching(&gradient);
+    assert_eq!(gradient.morse_numbers(), vec![1, 2, 1]);
+    assert_same_homology(&gradient);
+
+    let vertex = Simplex::new(0, vec![0]);
+    let vertex = torus.elements.values().find(|element| element.same_content(&vertex)).unwrap();
+    assert!(gradient.is_critical(vertex));
+    assert!(gradient.partner(vertex).is_none());
+  }
+
+  #[test]
+  fn test_morse_complex_detects_torsion() {
+    // The 6-vertex real projective plane: H₁ is Z/2, visible over Boolean but not over Mod7.
+    let mut projective_plane = SimplicialComplex::new();
+    for face in [
+      [0, 1, 2],
+      [0, 2, 3],
+      [0, 3, 4],
+      [0, 4, 5],
+      [0, 1, 5],
+      [1, 2, 4],
+      [2, 3, 5],
+      [1, 3, 4],
+      [1, 3, 5],
+      [2, 4, 5],
+    ] {
+      projective_plane.join_element(Simplex::new(2, face.to_vec()));
+    }
+    let gradient = DiscreteGradient::from_coreductions(&projective_plane);
+    assert_valid_matching(&gradient);
+    assert_same_homology(&gradient);
+
+    let morse_complex = gradient.morse_complex::<Boolean>();
+    assert_eq!(morse_complex.homology(1).betti_number, 1);
+    assert_eq!(gradient.morse_complex::<Mod7>().homology(1).betti_number, 0);
+  }
+
+  #[test]
+  fn test_lower_star_gradient() {
+    // A height function on a 4×4 grid of squares with two minima in opposite corners and a
+    // maximum in the middle.
+    let vertex = |i: usize, j: usize| 5 * j + i;
+    let mut grid = CubicalComplex::new();
+    for j in 0..4 {
+      for i in 0..4 {
+        grid.join_element(Cube::square([
+          vertex(i, j),
+          vertex(i + 1, j),
+          vertex(i, j + 1),
+          vertex(i + 1, j + 1),
+        ]));
+      }
+    }
+    let height = |cube: &Cube| {
+      let label = cube.vertices()[0];
+      let (i, j) = ((label % 5) as f64, (label / 5) as f64);
+      let to_minimum =
+        ((i - 0.0).powi(2) + (j - 0.0).powi(2)).min((i - 4.0).powi(2) + (j - 4.0).powi(2));
+      to_minimum - 0.1 * (i - j).powi(2) + 0.01 * i
+    };
+
+    let gradient = DiscreteGradient::lower_star(&grid, height);
+    assert_valid_matching(&gradient);
+    assert_same_homology(&gradient);
+
+    let minima = gradient.critical_cells(0);
+    assert_eq!(minima.len(), 2);
+    assert!(minima.iter().all(|minimum| [0, 24].contains(&minimum.vertices()[0])));
+    let morse_numbers = gradient.morse_numbers();
+    assert_eq!(morse_numbers[0] - morse_numbers[1] + morse_numbers[2], 1);
+  }
+
+  #[test]
+  fn test_lower_star_on_torus() {
+    let torus = torus();
+    let gradient =
+      DiscreteGradient::lower_star(&torus, |vertex: &Simplex| vertex.vertices()[0] as f64);
+    assert_valid_matching(&gradient);
+    assert_same_homology(&gradient);
+
+    let morse_numbers = gradient.morse_numbers();
+    assert_eq!(morse_numbers[0], 1);
+    assert!(morse_numbers[1] >= 2 && morse_numbers[2] >= 1);
+    assert_eq!(gradient.critical_cells(0)[0].vertices(), &[0]);
+  }
+}
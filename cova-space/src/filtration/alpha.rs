@@ -0,0 +1,452 @@
+//! # Delaunay Triangulations and Alpha Complexes
+//!
+//! This module builds the Delaunay triangulation of a point cloud and the **alpha complex**
+//! filtration on it. Alpha complexes have the same homotopy type as the union of balls around
+//! the points at every scale, like the Čech complex, but they only contain Delaunay simplices and
+//! are therefore far smaller than Vietoris-Rips complexes of the same point set.
+//!
+//! ## Mathematical Background
+//!
+//! A simplex $\sigma$ spanned by points of $X \subset \mathbb{R}^N$ is **Delaunay** if some
+//! $(N-1)$-sphere passes through its vertices and has no point of $X$ strictly inside. For points
+//! in general position the Delaunay simplices form a triangulation of the convex hull of $X$, the
+//! **Delaunay triangulation** $\text{Del}(X)$.
+//!
+//! For $r \geq 0$ the **alpha complex** is the nerve of the balls $B(x, r)$ intersected with the
+//! Voronoi cells of their centers:
+//! $$ \text{Alpha}_r(X) = \Big\\{ \sigma \in \text{Del}(X) : \bigcap_{x \in \sigma} \big( B(x, r)
+//! \cap \text{Vor}(x) \big) \neq \emptyset \Big\\}. $$
+//! By the nerve theorem $\text{Alpha}_r(X)$ is homotopy equivalent to $\bigcup_{x \in X} B(x,
+//! r)$.
+//!
+//! The **alpha value** $f(\sigma)$ at which a simplex enters is computed from the smallest
+//! circumsphere of $\sigma$, the one centered in the affine hull of its vertices, with radius
+//! $\rho(\sigma)$. A simplex is **attached** if a vertex of one of its cofaces lies strictly inside
+//! that sphere. Processing simplices from the top dimension down,
+//! $$ f(\sigma) = \begin{cases} \min_{\sigma < \tau} f(\tau) & \text{if } \sigma \text{ is
+//! attached,} \\\\ \rho(\sigma) & \text{otherwise,} \end{cases} $$
+//! with $f = 0$ on vertices. Filtration values are radii, so an edge of length $d$ that is not
+//! attached appears at $d / 2$, matching the $2\epsilon$ convention of
+//! [`VietorisRips`](super::vietoris_rips::VietorisRips).
+//!
+//! ## Algorithm
+//!
+//! The triangulation is computed with the incremental **Bowyer-Watson** algorithm: the points
+//! are placed inside a large enclosing simplex and inserted one at a time. Each insertion removes
+//! every simplex whose circumsphere strictly contains the new point and fills the resulting cavity
+//! with simplices joining its boundary facets to the point. Finally the simplices touching the
+//! enclosing simplex are dropped, keeping those of their faces that only use input points.
+//!
+//! The construction works for any dimension `N`, but the size of a Delaunay triangulation grows
+//! like $n^{\lceil N/2 \rceil}$ and the quadratic insertion is aimed at point clouds in two and
+//! three dimensions. Points that are not in general position (e.g. four cocircular points in the
+//! plane) are handled by breaking ties in insertion order, up to floating-point precision; exact
+//! duplicates of earlier points become isolated vertices.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use cova_algebra::{algebras::boolean::Boolean, tensors::SVector};
+//! use cova_space::{cloud::Cloud, filtration::alpha::Alpha};
+//!
+//! // Eight points on a circle of radius 1.
+//! let points = (0..8)
+//!   .map(|i| {
+//!     let angle = std::f64::consts::TAU * f64::from(i) / 8.0;
+//!     SVector::from([angle.cos(), angle.sin()])
+//!   })
+//!   .collect();
+//! let cloud: Cloud<2> = Cloud::new(points);
+//!
+//! let filtered = Alpha::<2>::new().build_filtration(&cloud, None);
+//!
+//! // The loop is born once neighbouring balls touch and dies when the disk is filled in.
+//! let diagrams = filtered.persistent_homology::<Boolean>();
+//! let interval = diagrams[1].intervals[0];
+//! assert!((interval.birth - (std::f64::consts::PI / 8.0).sin()).abs() < 1e-9);
+//! assert!((interval.death - 1.0).abs() < 1e-9);
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+use cova_algebra::tensors::{DMatrix, DVector, SVector};
+
+use crate::{
+  cloud::Cloud,
+  complexes::{FilteredComplex, Simplex, SimplicialComplex},
+  filtration::{Filtration, filtered_simplicial_complex},
+};
+
+/// Relative tolerance below which a point counts as lying on, rather than inside, a circumsphere.
+const INSPHERE_TOLERANCE: f64 = 1e-10;
+
+/// A builder for Delaunay triangulations and alpha complex filtrations of point clouds in
+/// $\mathbb{R}^N$.
+///
+/// It implements the [`Filtration`] trait, taking a [`Cloud`] and an optional maximal alpha value
+/// to produce a [`FilteredComplex`] whose filtration values are alpha radii.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Alpha<const N: usize>;
+
+/// A simplex of the triangulation under construction together with its circumsphere.
+struct DelaunayCell<const N: usize> {
+  vertices:       Vec<usize>,
+  center:         SVector<f64, N>,
+  radius_squared: f64,
+}
+
+impl<const N: usize> Alpha<N> {
+  /// Creates a new `Alpha` builder.
+  pub const fn new() -> Self { Self }
+
+  /// Computes the Delaunay triangulation of `cloud` as a [`SimplicialComplex`].
+  ///
+  /// Vertex labels are the indices of the points in the cloud. Every point appears as a vertex,
+  /// and the maximal simplices are the Delaunay $N$-simplices together with lower-dimensional
+  /// Delaunay simplices that are not faces of any of them (e.g. when all points are collinear).
+  pub fn delaunay_triangulation(&self, cloud: &Cloud<N>) -> SimplicialComplex {
+    let mut complex = SimplicialComplex::new();
+    for vertex in 0..cloud.points_ref().len() {
+      complex.join_element(Simplex::new(0, vec![vertex]));
+    }
+    for simplex in delaunay_simplices(cloud) {
+      complex.join_element(Simplex::from_vertices(simplex));
+    }
+    complex
+  }
+
+  /// Builds the alpha complex filtration of `cloud` as a single [`FilteredComplex`].
+  ///
+  /// Every Delaunay simplex carries its alpha value, so that
+  /// [`FilteredComplex::truncate`] at $r$ yields $\text{Alpha}_r(X)$. See the
+  /// [module documentation](self) for the definition.
+  ///
+  /// # Arguments
+  ///
+  /// * `cloud`: The input points.
+  /// * `max_alpha`: If given, only simplices with alpha value at most `max_alpha` are kept.
+  pub fn build_filtration(
+    &self,
+    cloud: &Cloud<N>,
+    max_alpha: Option<f64>,
+  ) -> FilteredComplex<Simplex> {
+    let points = cloud.points_ref();
+
+    // The Delaunay complex is the closure of the maximal simplices.
+    let mut by_dimension: Vec<HashSet<Vec<usize>>> = vec![HashSet::new(); N + 1];
+    by_dimension[0].extend((0..points.len()).map(|vertex| vec![vertex]));
+    for simplex in delaunay_simplices(cloud) {
+      insert_with_faces(&mut by_dimension, simplex);
+    }
+
+    let mut values: HashMap<Vec<usize>, f64> = HashMap::new();
+    // For each simplex, the smallest value of a coface it is attached to.
+    let mut attached: HashMap<Vec<usize>, f64> = HashMap::new();
+    for dimension in (0..=N).rev() {
+      for simplex in &by_dimension[dimension] {
+        let (_, radius_squared) =
+          circumsphere(&simplex.iter().map(|&v| points[v]).collect::<Vec<_>>())
+            .expect("Delaunay simplices are non-degenerate");
+        let value = attached.get(simplex).copied().unwrap_or_else(|| radius_squared.sqrt());
+        values.insert(simplex.clone(), value);
+        if dimension == 0 {
+          continue;
+        }
+
+        for (omitted, &opposite) in simplex.iter().enumerate() {
+          let mut facet = simplex.clone();
+          facet.remove(omitted);
+          let facet_points: Vec<_> = facet.iter().map(|&v| points[v]).collect();
+          let (facet_center, facet_radius_squared) =
+            circumsphere(&facet_points).expect("faces of Delaunay simplices are non-degenerate");
+          if (points[opposite] - facet_center).norm_squared() < facet_radius_squared {
+            let entry = attached.entry(facet).or_insert(f64::INFINITY);
+            *entry = entry.min(value);
+          }
+        }
+      }
+    }
+
+    let simplices = values
+      .into_iter()
+      .filter(|(_, value)| max_alpha.is_none_or(|max_alpha| *value <= max_alpha))
+      .collect();
+    filtered_simplicial_complex(simplices)
+  }
+}
+
+/// Implements the [`Filtration`] trait for `Alpha` to generate the whole alpha filtration at once.
+impl<const N: usize> Filtration for Alpha<N> {
+  type InputParameter = Option<f64>;
+  type InputSpace = Cloud<N>;
+  type OutputParameter = ();
+  type OutputSpace = FilteredComplex<Simplex>;
+
+  /// Builds the alpha filtration up to the optional `max_alpha`.
+  ///
+  /// This method delegates to [`Alpha::build_filtration`].
+  fn build(
+    &self,
+    cloud: &Self::InputSpace,
+    max_alpha: Self::InputParameter,
+    _output_param: &(),
+  ) -> Self::OutputSpace {
+    self.build_filtration(cloud, max_alpha)
+  }
+}
+
+/// Computes the smallest circumsphere of the given affinely independent points.
+///
+/// Returns its center, which lies in the affine hull of the points, and its squared radius, or
+/// `None` if the points are affinely dependent or `points` is empty.
+pub(crate) fn circumsphere<const N: usize>(
+  points: &[SVector<f64, N>],
+) -> Option<(SVector<f64, N>, f64)> {
+  let (origin, rest) = points.split_first()?;
+  if rest.is_empty() {
+    return Some((*origin, 0.0));
+  }
+
+  // Writing the center as origin + A·λ with A = [p₁ - p₀, …, p_k - p₀], equidistance from all
+  // points gives the normal equations 2 AᵀA λ = (|pⱼ - p₀|²)ⱼ.
+  let edges = DMatrix::from_fn(N, rest.len(), |row, col| rest[col][row] - origin[row]);
+  let gram = edges.transpose() * &edges * 2.0;
+  let rhs = DVector::from_fn(rest.len(), |col, _| (rest[col] - origin).norm_squared());
+  let lambda = gram.lu().solve(&rhs)?;
+  let offset = &edges * lambda;
+  let center = origin + SVector::from_fn(|row, _| offset[row]);
+  let radius_squared = (center - origin).norm_squared();
+  (radius_squared.is_finite() && center.iter().all(|x| x.is_finite()))
+    .then_some((center, radius_squared))
+}
+
+/// Inserts `simplex` and all of its faces into the per-dimension sets.
+fn insert_with_faces(by_dimension: &mut [HashSet<Vec<usize>>], simplex: Vec<usize>) {
+  let mut stack = vec![simplex];
+  while let Some(simplex) = stack.pop() {
+    if simplex.len() > 1 && !by_dimension[simplex.len() - 1].contains(&simplex) {
+      for omitted in 0..simplex.len() {
+        let mut facet = simplex.clone();
+        facet.remove(omitted);
+        stack.push(facet);
+      }
+    }
+    by_dimension[simplex.len() - 1].insert(simplex);
+  }
+}
+
+/// Returns simplices of the Delaunay triangulation of `cloud`, as sorted vertex lists, whose
+/// closure is the whole triangulation apart from isolated vertices.
+fn delaunay_simplices<const N: usize>(cloud: &Cloud<N>) -> Vec<Vec<usize>> {
+  let mut points = cloud.points_ref().clone();
+  let n = points.len();
+  if n == 0 {
+    return Vec::new();
+  }
+
+  // An enclosing simplex far larger than the bounding box: the standard simplex scaled by s
+  // contains the cube of side s / N, which we make extend `margin` beyond the points.
+  let lower = points.iter().fold(points[0], |acc, p| acc.inf(p));
+  let upper = points.iter().fold(points[0], |acc, p| acc.sup(p));
+  let extent = (upper - lower).max() + 1.0;
+  let margin = 1e3 * extent;
+  let corner = lower.add_scalar(-margin);
+  let scale = N as f64 * (extent + 2.0 * margin);
+  points.push(corner);
+  for axis in 0..N {
+    let mut vertex = corner;
+    vertex[axis] += scale;
+    points.push(vertex);
+  }
+
+  let make_cell = |vertices: Vec<usize>, points: &[SVector<f64, N>]| {
+    let cell_points: Vec<_> = vertices.iter().map(|&v| points[v]).collect();
+    circumsphere(&cell_points).map(|(center, radius_squared)| DelaunayCell {
+      vertices,
+      center,
+      radius_squared,
+    })
+  };
+  let mut cells: Vec<DelaunayCell<N>> =
+    make_cell((n..=n + N).collect(), &points).into_iter().collect();
+
+  for (index, point) in points.iter().enumerate().take(n) {
+    // Points on a circumsphere (up to rounding) do not invalidate it, so that ties between
+    // cospherical points are resolved consistently.
+    let (bad, good): (Vec<_>, Vec<_>) = cells.into_iter().partition(|cell| {
+      (point - cell.center).norm_squared() < cell.radius_squared * (1.0 - INSPHERE_TOLERANCE)
+    });
+    cells = good;
+
+    let mut facet_count: HashMap<Vec<usize>, usize> = HashMap::new();
+    for cell in &bad {
+      for omitted in 0..cell.vertices.len() {
+        let mut facet = cell.vertices.clone();
+        facet.remove(omitted);
+        *facet_count.entry(facet).or_insert(0) += 1;
+      }
+    }
+    for (mut facet, count) in facet_count {
+      if count == 1 {
+        facet.push(index);
+        facet.sort_unstable();
+        cells.extend(make_cell(facet, &points));
+      }
+    }
+  }
+
+  // Keep the simplices avoiding the enclosing simplex, and the faces of the others that do, so
+  // that hull simplices of degenerate point sets are not lost.
+  let simplices: HashSet<Vec<usize>> = cells
+    .into_iter()
+    .map(|cell| cell.vertices.into_iter().filter(|&v| v < n).collect::<Vec<_>>())
+    .filter(|simplex| simplex.len() > 1)
+    .collect();
+  let mut simplices: Vec<Vec<usize>> = simplices.into_iter().collect();
+  simplices.sort_unstable();
+  simplices
+}
+
+#[cfg(test)]
+mod tests {
+  use cova_algebra::algebras::boolean::Boolean;
+
+  use super::*;
+
+  /// Deterministic pseudo-random points in general position.
+  fn scattered<const N: usize>(count: usize) -> Cloud<N> {
+    let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+    let mut next = || {
+      state = state.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+      (state >> 11) as f64 / (1_u64 << 53) as f64
+    };
+    Cloud::new((0..count).map(|_| SVector::from_fn(|_, _| next())).collect())
+  }
+
+  fn assert_empty_circumspheres<const N: usize>(cloud: &Cloud<N>, complex: &SimplicialComplex) {
+    let points = cloud.points_ref();
+    for simplex in complex.elements_of_dimension(N) {
+      let vertices: Vec<_> = simplex.vertices().iter().map(|&v| points[v]).collect();
+      let (center, radius_squared) = circumsphere(&vertices).unwrap();
+      for (index, point) in points.iter().enumerate() {
+        if !simplex.vertices().contains(&index) {
+          assert!((point - center).norm_squared() > radius_squared * (1.0 - 1e-9));
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn test_circumsphere() {
+    let (center, radius_squared) =
+      circumsphere(&[SVector::from([0.0, 0.0]), SVector::from([2.0, 0.0])]).unwrap();
+    assert_eq!(center, SVector::from([1.0, 0.0]));
+    assert!((radius_squared - 1.0).abs() < 1e-12);
+
+    let (center, radius_squared) = circumsphere(&[
+      SVector::from([0.0, 0.0, 0.0]),
+      SVector::from([2.0, 0.0, 0.0]),
+      SVector::from([0.0, 2.0, 0.0]),
+    ])
+    .unwrap();
+    assert!((center - SVector::from([1.0, 1.0, 0.0])).norm() < 1e-12);
+    assert!((radius_squared - 2.0).abs() < 1e-12);
+
+    assert!(
+      circumsphere(&[
+        SVector::from([0.0, 0.0]),
+        SVector::from([1.0, 1.0]),
+        SVector::from([2.0, 2.0])
+      ])
+      .is_none()
+    );
+  }
+
+  #[test]
+  fn test_delaunay_triangulation_2d() {
+    let cloud = scattered::<2>(40);
+    let complex = Alpha::<2>::new().delaunay_triangulation(&cloud);
+    assert_eq!(complex.elements_of_dimension(0).len(), 40);
+    assert_eq!(complex.euler_characteristic(), 1);
+    assert_eq!(complex.homology::<Boolean>(1).betti_number, 0);
+    assert_empty_circumspheres(&cloud, &complex);
+
+    // A square grid is maximally degenerate, with four cocircular points in every cell.
+    let grid =
+      Cloud::new((0..25).map(|i| SVector::from([f64::from(i % 5), f64::from(i / 5)])).collect());
+    let complex = Alpha::<2>::new().delaunay_triangulation(&grid);
+    assert_eq!(complex.f_vector(), vec![25, 56, 32]);
+    assert_empty_circumspheres(&grid, &complex);
+
+    // Collinear points are triangulated by the path through them.
+    let line = Cloud::new((0..5).map(|i| SVector::from([f64::from(i * i), 0.0])).collect());
+    let path = Alpha::<2>::new().delaunay_triangulation(&line);
+    assert_eq!(path.f_vector(), vec![5, 4]);
+  }
+
+  #[test]
+  fn test_delaunay_triangulation_3d() {
+    let cloud = scattered::<3>(25);
+    let complex = Alpha::<3>::new().delaunay_triangulation(&cloud);
+    assert_eq!(complex.elements_of_dimension(0).len(), 25);
+    assert_eq!(complex.euler_characteristic(), 1);
+    assert_empty_circumspheres(&cloud, &complex);
+  }
+
+  #[test]
+  fn test_alpha_values() {
+    // An obtuse triangle: its longest edge is attached to the apex, so it only appears together
+    // with the triangle at the circumradius.
+    let cloud = Cloud::new(vec![
+      SVector::from([0.0, 0.0]),
+      SVector::from([4.0, 0.0]),
+      SVector::from([2.0, 1.0]),
+    ]);
+    let filtered = Alpha::<2>::new().build_filtration(&cloud, None);
+    let value = |vertices: Vec<usize>| {
+      let simplex = filtered
+        .complex()
+        .elements
+        .values()
+        .find(|simplex| simplex.vertices() == vertices.as_slice())
+        .unwrap();
+      filtered.value(simplex).unwrap()
+    };
+
+    assert_eq!(value(vec![0]), 0.0);
+    assert!((value(vec![0, 2]) - 5.0_f64.sqrt() / 2.0).abs() < 1e-12);
+    assert!((value(vec![0, 1, 2]) - 2.5).abs() < 1e-12);
+    assert!((value(vec![0, 1]) - 2.5).abs() < 1e-12);
+    assert!(filtered.is_monotone());
+
+    let truncated = Alpha::<2>::new().build_filtration(&cloud, Some(2.0));
+    assert_eq!(truncated.complex().f_vector(), vec![3, 2]);
+  }
+
+  #[test]
+  fn test_alpha_filtration_of_annulus() {
+    // Points on two rings: the alpha complex has the homotopy type of the union of balls.
+    let points: Vec<_> = (0..30)
+      .map(|i| {
+        let angle = std::f64::consts::TAU * (f64::from(i % 15) + 0.3 * f64::from(i / 15)) / 15.0;
+        let radius = if i < 15 { 1.0 } else { 1.3 };
+        SVector::from([radius * angle.cos(), radius * angle.sin()])
+      })
+      .collect();
+    let cloud = Cloud::new(points);
+    let filtered = Alpha::<2>::new().build_filtration(&cloud, None);
+    assert!(filtered.is_monotone());
+    assert_eq!(filtered.len(), Alpha::<2>::new().delaunay_triangulation(&cloud).elements.len());
+
+    let annulus = filtered.truncate(0.3);
+    assert_eq!(annulus.homology::<Boolean>(0).betti_number, 1);
+    assert_eq!(annulus.homology::<Boolean>(1).betti_number, 1);
+    assert!(annulus.elements.values().all(|simplex| simplex.dimension() <= 2));
+
+    let diagrams = filtered.persistent_homology::<Boolean>();
+    assert_eq!(
+      diagrams[1].intervals.iter().filter(|interval| interval.death - interval.birth > 0.3).count(),
+      1
+    );
+  }
+}
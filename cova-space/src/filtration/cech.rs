@@ -0,0 +1,400 @@
+//! # Čech Complex Construction
+//!
+//! This module implements the construction of Čech complexes, the nerve of a union of balls
+//! centered at the points of a cloud.
+//!
+//! ## Definition
+//!
+//! Given a finite set of points $X = \\{x_1, x_2, \dots, x_n\\} \subset \mathbb{R}^N$ and a
+//! radius $\epsilon > 0$, the **Čech complex** $\check{C}_\epsilon(X)$ contains a simplex
+//! $[x_{i_0}, \dots, x_{i_k}]$ if and only if the open balls of radius $\epsilon$ around its
+//! vertices have a common point:
+//! $$ [x_{i_0}, \dots, x_{i_k}] \in \check{C}_\epsilon(X) \iff \bigcap_{j=0}^k B(x_{i_j}, \epsilon)
+//! \neq \emptyset \iff r(x_{i_0}, \dots, x_{i_k}) < \epsilon, $$
+//! where $r$ denotes the radius of the **minimal enclosing ball** of the points. Since balls are
+//! convex, the nerve theorem guarantees that $\check{C}_\epsilon(X)$ is homotopy equivalent to
+//! $\bigcup_{x \in X} B(x, \epsilon)$.
+//!
+//! ## Relation to Vietoris-Rips
+//!
+//! [`VietorisRips`](super::vietoris_rips::VietorisRips) uses the same radius convention but only
+//! asks for the balls to intersect *pairwise*, i.e. for all pairwise distances to be less than
+//! $2\epsilon$. Both complexes therefore share their 1-skeleton, and
+//! $$ \check{C}_\epsilon(X) \subseteq VR_\epsilon(X) \subseteq \check{C}_{2\epsilon}(X). $$
+//! An equilateral triangle of side length 1 shows the difference: for $\frac{1}{2} < \epsilon <
+//! \frac{1}{\sqrt{3}}$ its three balls intersect pairwise but have no common point, so the Rips
+//! complex contains the triangle while the Čech complex is a hollow loop, as is the union of
+//! balls.
+//!
+//! ## Algorithm
+//!
+//! Candidate simplices are the cliques of the neighborhood graph at distance $2\epsilon$, which
+//! are enumerated incrementally exactly as for the Vietoris-Rips complex. The minimal enclosing
+//! ball of each candidate is determined by at most $N + 1$ of its points on the boundary, and is
+//! found as the smallest circumsphere of such a support set that contains all the points. Since
+//! the enclosing radius can only grow when points are added, candidates whose radius is too large
+//! are not extended any further.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use cova_algebra::{algebras::boolean::Boolean, tensors::SVector};
+//! use cova_space::{
+//!   cloud::Cloud,
+//!   complexes::SimplicialComplex,
+//!   filtration::{cech::Cech, vietoris_rips::VietorisRips},
+//! };
+//!
+//! // An equilateral triangle with side length 1.
+//! let cloud: Cloud<2> = Cloud::new(vec![
+//!   SVector::from([0.0, 0.0]),
+//!   SVector::from([1.0, 0.0]),
+//!   SVector::from([0.5, 3.0_f64.sqrt() / 2.0]),
+//! ]);
+//!
+//! let rips = VietorisRips::<2, SimplicialComplex>::new().build_complex(&cloud, 0.55);
+//! let cech = Cech::<2, SimplicialComplex>::new().build_complex(&cloud, 0.55);
+//!
+//! assert_eq!(rips.elements_of_dimension(2).len(), 1);
+//! assert!(cech.elements_of_dimension(2).is_empty());
+//! assert_eq!(cech.homology::<Boolean>(1).betti_number, 1);
+//! ```
+//!
+//! To study all scales at once, use [`Cech::build_filtration`], which records the enclosing
+//! radius of every simplex in a single [`FilteredComplex`].
+
+use std::marker::PhantomData;
+
+use cova_algebra::tensors::SVector;
+
+#[cfg(feature = "parallel")]
+use crate::filtration::ParallelFiltration;
+use crate::{
+  cloud::Cloud,
+  complexes::{Complex, FilteredComplex, Simplex, SimplicialComplex},
+  filtration::{
+    Filtration,
+    alpha::circumsphere,
+    filtered_simplicial_complex,
+    vietoris_rips::{for_each_clique, neighborhood_graph},
+  },
+};
+
+/// Relative tolerance used when testing whether a point lies inside a candidate enclosing ball.
+const ENCLOSING_TOLERANCE: f64 = 1e-12;
+
+/// A struct that allows construction of Čech complexes.
+///
+/// It implements the [`Filtration`] trait, taking a [`Cloud`] of points and a radius `epsilon`
+/// to produce a [`SimplicialComplex`], or the whole filtration as a [`FilteredComplex`].
+///
+/// A $k$-simplex `[v0, v1, ..., vk]` is included in the Čech complex if the minimal enclosing
+/// ball of its vertices has radius less than `epsilon`.
+///
+/// # Type Parameters
+///
+/// * `N`: The dimension of the Euclidean space where the points reside.
+/// * `O`: The output type of the filtration, either [`SimplicialComplex`] or
+///   [`FilteredComplex<Simplex>`].
+pub struct Cech<const N: usize, O> {
+  max_dimension: Option<usize>,
+  _phantom:      PhantomData<[f64; N]>,
+  _output_space: PhantomData<O>,
+}
+
+impl<const N: usize, O> Cech<N, O> {
+  /// Creates a new `Cech` builder.
+  pub const fn new() -> Self {
+    Self { max_dimension: None, _phantom: PhantomData, _output_space: PhantomData }
+  }
+
+  /// Limits the constructed complexes to simplices of dimension at most `max_dimension`.
+  pub const fn with_max_dimension(mut self, max_dimension: usize) -> Self {
+    self.max_dimension = Some(max_dimension);
+    self
+  }
+
+  /// Returns the maximum simplex dimension, or `None` if the dimension is unbounded.
+  pub const fn max_dimension(&self) -> Option<usize> { self.max_dimension }
+}
+
+impl<const N: usize> Cech<N, Complex<Simplex>> {
+  /// Builds the Čech complex of `cloud` for the ball radius `epsilon`.
+  ///
+  /// A set of points spans a simplex if and only if its minimal enclosing ball has radius less
+  /// than `epsilon`, i.e. if the open `epsilon`-balls around the points have a common point.
+  /// All points are added as vertices, labelled by their index in the cloud.
+  pub fn build_complex(&self, cloud: &Cloud<N>, epsilon: f64) -> SimplicialComplex {
+    let mut complex = SimplicialComplex::new();
+    for_each_cech_simplex(cloud, epsilon, self.max_dimension, |simplex, _| {
+      complex.join_element(Simplex::new(simplex.len() - 1, simplex.to_vec()));
+    });
+    complex
+  }
+}
+
+impl<const N: usize> Cech<N, FilteredComplex<Simplex>> {
+  /// Builds the whole Čech filtration of `cloud` as a single [`FilteredComplex`].
+  ///
+  /// Every simplex carries the radius of the minimal enclosing ball of its vertices as its
+  /// filtration value. The complex built by [`Cech::build_complex`] at `epsilon` consists of the
+  /// simplices with value strictly below `epsilon`, which agrees with
+  /// [`FilteredComplex::truncate`] at `epsilon` unless some radius equals that value exactly.
+  ///
+  /// # Arguments
+  ///
+  /// * `cloud`: The input points.
+  /// * `max_epsilon`: If given, only simplices that appear in the complex at `max_epsilon` are
+  ///   kept, i.e. the vertices and the simplices with radius strictly below `max_epsilon`.
+  ///
+  /// Simplices are also limited by [`Cech::max_dimension`], if set.
+  pub fn build_filtration(
+    &self,
+    cloud: &Cloud<N>,
+    max_epsilon: Option<f64>,
+  ) -> FilteredComplex<Simplex> {
+    let mut simplices = Vec::new();
+    for_each_cech_simplex(
+      cloud,
+      max_epsilon.unwrap_or(f64::INFINITY),
+      self.max_dimension,
+      |simplex, radius| simplices.push((simplex.to_vec(), radius)),
+    );
+
+    filtered_simplicial_complex(simplices)
+  }
+}
+
+/// Calls `visit` with every simplex of the Čech complex at `epsilon` and its enclosing radius.
+fn for_each_cech_simplex<const N: usize>(
+  cloud: &Cloud<N>,
+  epsilon: f64,
+  max_dimension: Option<usize>,
+  mut visit: impl FnMut(&[usize], f64),
+) {
+  let points = cloud.points_ref();
+  let graph = neighborhood_graph(cloud, 2.0 * epsilon);
+  for_each_clique(&graph, max_dimension.unwrap_or(usize::MAX), |clique| {
+    let clique_points: Vec<_> = clique.iter().map(|&v| points[v]).collect();
+    let radius = minimal_enclosing_radius(&clique_points);
+    // The enclosing radius is monotone, so no extension of a rejected clique can be accepted.
+    // Vertices are always accepted, as in the Vietoris-Rips complex.
+    let accepted = clique.len() == 1 || radius < epsilon;
+    if accepted {
+      visit(clique, radius);
+    }
+    accepted
+  });
+}
+
+/// Returns the radius of the smallest ball containing all of `points`.
+///
+/// Returns 0 for an empty slice.
+fn minimal_enclosing_radius<const N: usize>(points: &[SVector<f64, N>]) -> f64 {
+  if points.is_empty() {
+    return 0.0;
+  }
+  let mut best = f64::INFINITY;
+  let mut support = Vec::with_capacity(N + 1);
+  search_supports(points, 0, &mut support, &mut best);
+  best
+}
+
+/// Extends `support` by each point from index `start` on, recording the smallest circumsphere
+/// that contains all points in `best`.
+///
+/// The smallest circumsphere of a set can only grow when points are added to it, so supports
+/// whose sphere is already larger than `best`, or which are affinely dependent, are not extended.
+fn search_supports<const N: usize>(
+  points: &[SVector<f64, N>],
+  start: usize,
+  support: &mut Vec<SVector<f64, N>>,
+  best: &mut f64,
+) {
+  for index in start..points.len() {
+    support.push(points[index]);
+    if let Some((center, radius_squared)) = circumsphere(support) {
+      let radius = radius_squared.sqrt();
+      if radius < *best {
+        let tolerance = radius_squared * ENCLOSING_TOLERANCE + ENCLOSING_TOLERANCE;
+        if points.iter().all(|point| (point - center).norm_squared() <= radius_squared + tolerance)
+        {
+          *best = radius;
+        } else if support.len() <= N {
+          search_supports(points, index + 1, support, best);
+        }
+      }
+    }
+    support.pop();
+  }
+}
+
+/// Provides a default constructor for `Cech` when the output is [`SimplicialComplex`].
+impl<const N: usize> Default for Cech<N, SimplicialComplex> {
+  fn default() -> Self { Self::new() }
+}
+
+/// Implements the [`Filtration`] trait for `Cech` to generate a [`SimplicialComplex`].
+impl<const N: usize> Filtration for Cech<N, Complex<Simplex>> {
+  type InputParameter = f64;
+  type InputSpace = Cloud<N>;
+  type OutputParameter = ();
+  type OutputSpace = Complex<Simplex>;
+
+  /// Builds the Čech [`SimplicialComplex`].
+  ///
+  /// This method delegates to [`Cech::build_complex`].
+  fn build(
+    &self,
+    cloud: &Self::InputSpace,
+    epsilon: Self::InputParameter,
+    _output_param: &(),
+  ) -> Self::OutputSpace {
+    self.build_complex(cloud, epsilon)
+  }
+}
+
+/// Provides a default constructor for `Cech` when the output is a [`FilteredComplex`].
+impl<const N: usize> Default for Cech<N, FilteredComplex<Simplex>> {
+  fn default() -> Self { Self::new() }
+}
+
+/// Implements the [`Filtration`] trait for `Cech` to generate the whole filtration at once.
+impl<const N: usize> Filtration for Cech<N, FilteredComplex<Simplex>> {
+  type InputParameter = Option<f64>;
+  type InputSpace = Cloud<N>;
+  type OutputParameter = ();
+  type OutputSpace = FilteredComplex<Simplex>;
+
+  /// Builds the Čech filtration up to the optional `max_epsilon`.
+  ///
+  /// This method delegates to [`Cech::build_filtration`].
+  fn build(
+    &self,
+    cloud: &Self::InputSpace,
+    max_epsilon: Self::InputParameter,
+    _output_param: &(),
+  ) -> Self::OutputSpace {
+    self.build_filtration(cloud, max_epsilon)
+  }
+}
+
+/// Implements [`ParallelFiltration`] for `Cech` targeting [`SimplicialComplex`] output.
+#[cfg(feature = "parallel")]
+impl<const N: usize> ParallelFiltration for Cech<N, SimplicialComplex> where Cloud<N>: Sync {}
+
+#[cfg(test)]
+mod tests {
+  use cova_algebra::algebras::boolean::Boolean;
+
+  use super::*;
+  use crate::filtration::vietoris_rips::VietorisRips;
+
+  fn irregular_grid() -> Cloud<2> {
+    Cloud::new(
+      (0..12)
+        .map(|i| {
+          let (x, y) = (f64::from(i % 4), f64::from(i / 4));
+          SVector::from([x + 0.13 * (y * 1.7).sin(), y + 0.11 * (x * 2.3).cos()])
+        })
+        .collect(),
+    )
+  }
+
+  #[test]
+  fn test_minimal_enclosing_radius() {
+    // An acute triangle is enclosed by its circumcircle.
+    let equilateral = [
+      SVector::from([0.0, 0.0]),
+      SVector::from([1.0, 0.0]),
+      SVector::from([0.5, 3.0_f64.sqrt() / 2.0]),
+    ];
+    assert!((minimal_enclosing_radius(&equilateral) - 1.0 / 3.0_f64.sqrt()).abs() < 1e-12);
+
+    // An obtuse triangle is enclosed by the ball on its longest edge.
+    let obtuse = [SVector::from([0.0, 0.0]), SVector::from([4.0, 0.0]), SVector::from([2.0, 1.0])];
+    assert!((minimal_enclosing_radius(&obtuse) - 2.0).abs() < 1e-12);
+
+    // Collinear and repeated points.
+    let collinear = [
+      SVector::from([0.0, 0.0, 0.0]),
+      SVector::from([1.0, 1.0, 1.0]),
+      SVector::from([3.0, 3.0, 3.0]),
+      SVector::from([1.0, 1.0, 1.0]),
+    ];
+    assert!((minimal_enclosing_radius(&collinear) - 1.5 * 3.0_f64.sqrt()).abs() < 1e-12);
+    assert_eq!(minimal_enclosing_radius(&[SVector::from([1.0, 2.0])]), 0.0);
+
+    // The corners of a cube together with its center.
+    let mut cube: Vec<SVector<f64, 3>> = (0..8)
+      .map(|i| SVector::from([f64::from(i & 1), f64::from((i >> 1) & 1), f64::from(i >> 2)]))
+      .collect();
+    cube.push(SVector::from([0.5, 0.5, 0.5]));
+    assert!((minimal_enclosing_radius(&cube) - 3.0_f64.sqrt() / 2.0).abs() < 1e-12);
+  }
+
+  #[test]
+  fn test_cech_is_subcomplex_of_rips() {
+    let cloud = irregular_grid();
+    for epsilon in [0.4, 0.6, 0.75, 1.0] {
+      let cech = Cech::<2, SimplicialComplex>::new().build_complex(&cloud, epsilon);
+      let rips = VietorisRips::<2, SimplicialComplex>::new().build_complex(&cloud, epsilon);
+
+      assert_eq!(cech.elements_of_dimension(1).len(), rips.elements_of_dimension(1).len());
+      for simplex in cech.elements.values() {
+        assert!(rips.elements.values().any(|other| other.same_content(simplex)));
+        let vertices: Vec<_> = simplex.vertices().iter().map(|&v| cloud.points_ref()[v]).collect();
+        assert!(minimal_enclosing_radius(&vertices) < epsilon);
+      }
+    }
+  }
+
+  #[test]
+  fn test_cech_matches_union_of_balls() {
+    // Six points on a unit circle: the union of balls has a hole until the radius reaches 1, and
+    // the Čech complex detects it while the Rips complex fills it in much earlier.
+    let points = (0..6)
+      .map(|i| {
+        let angle = std::f64::consts::TAU * f64::from(i) / 6.0;
+        SVector::from([angle.cos(), angle.sin()])
+      })
+      .collect();
+    let cloud = Cloud::new(points);
+
+    let cech = Cech::<2, SimplicialComplex>::new().build_complex(&cloud, 0.9);
+    assert_eq!(cech.homology::<Boolean>(1).betti_number, 1);
+    let rips = VietorisRips::<2, SimplicialComplex>::new().build_complex(&cloud, 0.9);
+    assert_eq!(rips.homology::<Boolean>(1).betti_number, 0);
+
+    let cech = Cech::<2, SimplicialComplex>::new().build_complex(&cloud, 1.01);
+    assert_eq!(cech.homology::<Boolean>(1).betti_number, 0);
+    assert_eq!(cech.elements_of_dimension(5).len(), 1);
+  }
+
+  #[test]
+  fn test_cech_filtration_matches_complexes() {
+    let cloud = irregular_grid();
+    let builder = Cech::<2, SimplicialComplex>::new().with_max_dimension(3);
+    let filtered = Cech::<2, FilteredComplex<Simplex>>::new()
+      .with_max_dimension(3)
+      .build_filtration(&cloud, None);
+    assert!(filtered.is_monotone());
+
+    for epsilon in [0.3, 0.55, 0.7, 0.95] {
+      let complex = builder.build_complex(&cloud, epsilon);
+      let truncated = filtered.truncate(epsilon);
+      assert_eq!(complex.f_vector(), truncated.f_vector(), "epsilon {epsilon}");
+    }
+
+    let bounded = Cech::<2, FilteredComplex<Simplex>>::new().build_filtration(&cloud, Some(0.7));
+    let complex = Cech::<2, SimplicialComplex>::new().build_complex(&cloud, 0.7);
+    assert_eq!(bounded.complex().f_vector(), complex.f_vector());
+
+    // Vertices are present at every scale, as in the Vietoris-Rips complex.
+    let points = cloud.points_ref().len();
+    assert_eq!(builder.build_complex(&cloud, 0.0).f_vector(), vec![points]);
+    let empty_scale =
+      Cech::<2, FilteredComplex<Simplex>>::new().build_filtration(&cloud, Some(0.0));
+    assert_eq!(empty_scale.complex().f_vector(), vec![points]);
+  }
+}
@@ -1,6 +1,13 @@
 //! Defines traits for building filtered topological spaces.
 
+pub mod alpha;
+pub mod cech;
+pub mod cubical;
+pub mod sparse_rips;
 pub mod vietoris_rips;
+pub mod witness;
+
+use crate::complexes::{FilteredComplex, Simplex};
 
 /// A trait for processes that construct an output space from an input space
 /// based on a given parameter. This is a core concept in filtrations,
@@ -48,6 +55,24 @@ pub trait Filtration {
   }
 }
 
+/// Builds a filtered simplicial complex from simplices given as vertex lists with their filtration
+/// values.
+///
+/// The list must be closed under taking faces, with every face valued no higher than its cofaces.
+pub(super) fn filtered_simplicial_complex(
+  mut simplices: Vec<(Vec<usize>, f64)>,
+) -> FilteredComplex<Simplex> {
+  // Inserting in filtration order means every face is already present with a smaller value.
+  simplices.sort_by(|(a, value_a), (b, value_b)| {
+    value_a.total_cmp(value_b).then(a.len().cmp(&b.len())).then_with(|| a.cmp(b))
+  });
+  let mut filtered = FilteredComplex::new();
+  for (vertices, value) in simplices {
+    filtered.insert(Simplex::from_vertices(vertices), value);
+  }
+  filtered
+}
+
 #[cfg(feature = "parallel")] use rayon::prelude::*;
 
 #[cfg(feature = "parallel")]
@@ -0,0 +1,351 @@
+//! # Sparse Vietoris-Rips Filtrations
+//!
+//! This module implements Sheehy's **sparse Rips filtration**, an approximation of the
+//! Vietoris-Rips filtration whose size is linear in the number of points for a fixed
+//! approximation parameter and dimension, with a provable bound on how far its persistence
+//! diagram is from that of the exact filtration.
+//!
+//! ## Mathematical Background
+//!
+//! A **greedy permutation** $p_1, \dots, p_n$ of a point cloud $X$ starts from an arbitrary
+//! point and repeatedly adds the point farthest from those already chosen. The **insertion
+//! radius** $\lambda_{p_i} = d(p_i, \\{p_1, \dots, p_{i-1}\\})$ is the distance at which $p_i$
+//! was chosen, with $\lambda_{p_1} = \infty$; every point of $X$ lies within $\lambda_{p_i}$ of
+//! $\\{p_1, \dots, p_{i-1}\\}$.
+//!
+//! For an approximation parameter $0 < \varepsilon < 1$, each point gets a weight at scale
+//! $\alpha$ that is zero until $\lambda_p / \varepsilon$, then grows with slope one, and grows
+//! with slope $\varepsilon$ after $\lambda_p / (\varepsilon (1 - \varepsilon))$:
+//! $$ w_p(\alpha) = \begin{cases} 0 & \alpha \leq \lambda_p / \varepsilon, \\\\ \alpha - \lambda_p
+//! / \varepsilon & \lambda_p / \varepsilon < \alpha < \lambda_p / (\varepsilon (1 - \varepsilon)),
+//! \\\\ \varepsilon \alpha & \text{otherwise.} \end{cases} $$
+//! The **relaxed Rips complex** at scale $\alpha$ uses the relaxed distance $\hat d_\alpha(p, q) =
+//! d(p, q) + w_p(\alpha) + w_q(\alpha)$ in place of $d$, and the sparse complex $Q_\alpha$
+//! restricts it to the points with $\alpha \leq \lambda_p / (\varepsilon (1 - \varepsilon))$: once
+//! a point's weight has reached its final slope, it is well approximated by an earlier point and is
+//! removed. The **sparse Rips filtration** is $S_\alpha = \bigcup_{\beta \leq \alpha} Q_\beta$.
+//!
+//! Sheehy shows that $S_\alpha$ and the Rips filtration are multiplicatively interleaved with a
+//! factor that tends to $1$ as $\varepsilon \to 0$, so their persistence diagrams agree up to a
+//! factor $1 + O(\varepsilon)$ in every coordinate, while the number of simplices in $S$ is
+//! $O(n)$ for fixed $\varepsilon$ and dimension in spaces of bounded doubling dimension.
+//!
+//! ## Filtration Values
+//!
+//! Filtration values use the same units as
+//! [`VietorisRips::build_filtration`](super::vietoris_rips::VietorisRips::build_filtration): an
+//! edge that is born while neither endpoint carries weight appears at its length $d(p, q) = 2
+//! \alpha$. In general an edge is born at twice the smallest $\alpha$ with $\hat d_\alpha(p, q)
+//! \leq 2 \alpha$, provided both endpoints are still present, and a simplex is born at the
+//! largest value of its edges, provided none of its vertices has been removed by then.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use cova_algebra::{algebras::boolean::Boolean, tensors::SVector};
+//! use cova_space::{cloud::Cloud, filtration::sparse_rips::SparseRips};
+//!
+//! // A densely sampled circle.
+//! let points = (0..200)
+//!   .map(|i| {
+//!     let angle = std::f64::consts::TAU * f64::from(i) / 200.0;
+//!     SVector::from([angle.cos(), angle.sin()])
+//!   })
+//!   .collect();
+//! let cloud: Cloud<2> = Cloud::new(points);
+//!
+//! let filtered = SparseRips::<2>::new(0.5).with_max_dimension(2).build_filtration(&cloud, None);
+//!
+//! // Far fewer edges than the 19900 of the full Rips filtration, but the same loop.
+//! assert!(filtered.complex().elements_of_dimension(1).len() < 2000);
+//! let diagrams = filtered.persistent_homology::<Boolean>();
+//! let longest = diagrams[1].intervals.iter().map(|i| i.death - i.birth).fold(0.0, f64::max);
+//! assert!(longest > 1.0);
+//! ```
+
+use std::collections::HashMap;
+
+use crate::{
+  cloud::Cloud,
+  complexes::{FilteredComplex, Simplex},
+  definitions::MetricSpace,
+  filtration::{
+    Filtration, filtered_simplicial_complex, vietoris_rips::for_each_clique,
+    witness::farthest_point_sampling,
+  },
+};
+
+/// A builder for sparse Rips filtrations.
+///
+/// It implements the [`Filtration`] trait, taking a [`Cloud`] and an optional maximal scale to
+/// produce a [`FilteredComplex`] that approximates the Vietoris-Rips filtration. Vertices are
+/// labelled by their index in the cloud.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SparseRips<const N: usize> {
+  approximation: f64,
+  max_dimension: Option<usize>,
+}
+
+impl<const N: usize> SparseRips<N> {
+  /// Creates a new sparse Rips builder with approximation parameter $\varepsilon$ =
+  /// `approximation`.
+  ///
+  /// Smaller values give a better approximation of the Rips filtration at the cost of a larger
+  /// complex.
+  ///
+  /// # Panics
+  ///
+  /// Panics unless `0 < approximation < 1`.
+  pub fn new(approximation: f64) -> Self {
+    assert!(
+      approximation > 0.0 && approximation < 1.0,
+      "the approximation parameter must lie strictly between 0 and 1"
+    );
+    Self { approximation, max_dimension: None }
+  }
+
+  /// Limits the constructed complexes to simplices of dimension at most `max_dimension`.
+  pub const fn with_max_dimension(mut self, max_dimension: usize) -> Self {
+    self.max_dimension = Some(max_dimension);
+    self
+  }
+
+  /// Returns the approximation parameter $\varepsilon$.
+  pub const fn approximation(&self) -> f64 { self.approximation }
+
+  /// Returns the maximum simplex dimension, or `None` if the dimension is unbounded.
+  pub const fn max_dimension(&self) -> Option<usize> { self.max_dimension }
+
+  /// Builds the sparse Rips filtration of `cloud` as a single [`FilteredComplex`].
+  ///
+  /// The greedy permutation starts at the first point of the cloud. Candidate edges are found by
+  /// comparing all pairs of points, so the running time is quadratic in the number of points even
+  /// though the output is linear.
+  ///
+  /// # Arguments
+  ///
+  /// * `cloud`: The input points.
+  /// * `max_epsilon`: If given, only simplices with value strictly below `2.0 * max_epsilon` are
+  ///   kept, matching the threshold of [`VietorisRips`](super::vietoris_rips::VietorisRips).
+  pub fn build_filtration(
+    &self,
+    cloud: &Cloud<N>,
+    max_epsilon: Option<f64>,
+  ) -> FilteredComplex<Simplex> {
+    let points = cloud.points_ref();
+    let threshold = max_epsilon.map_or(f64::INFINITY, |epsilon| 2.0 * epsilon);
+    let permutation = greedy_permutation(cloud);
+    let count = permutation.len();
+    let epsilon = self.approximation;
+
+    // Vertices are indexed by their position in the greedy permutation while building.
+    let removal: Vec<f64> =
+      permutation.iter().map(|&(_, radius)| 2.0 * radius / (epsilon * (1.0 - epsilon))).collect();
+    let mut edge_values: HashMap<(usize, usize), f64> = HashMap::new();
+    let mut graph: Vec<Vec<usize>> = vec![Vec::new(); count];
+    for a in 0..count {
+      for b in a + 1..count {
+        let distance = Cloud::<N>::distance(points[permutation[a].0], points[permutation[b].0]);
+        if let Some(value) =
+          self.edge_birth(distance, permutation[a].1, permutation[b].1).filter(|v| *v < threshold)
+        {
+          edge_values.insert((a, b), value);
+          graph[a].push(b);
+        }
+      }
+    }
+
+    let mut simplices = Vec::new();
+    for_each_clique(&graph, self.max_dimension.unwrap_or(usize::MAX), |clique| {
+      let value = clique
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &a)| clique[i + 1..].iter().map(move |&b| (a, b)))
+        .map(|(a, b)| edge_values[&(a, b)])
+        .fold(0.0, f64::max);
+      // Later vertices are removed earlier, so `clique` is only present while its last vertex is.
+      let alive = clique.len() == 1 || value <= removal[clique[clique.len() - 1]];
+      if alive {
+        let mut vertices: Vec<usize> = clique.iter().map(|&i| permutation[i].0).collect();
+        vertices.sort_unstable();
+        simplices.push((vertices, value));
+      }
+      alive
+    });
+
+    filtered_simplicial_complex(simplices)
+  }
+
+  /// Returns the filtration value at which the edge between points with insertion radii
+  /// `lambda_p` and `lambda_q` at the given `distance` is born, or `None` if one of its endpoints
+  /// is removed first.
+  ///
+  /// The relaxed condition $g(\alpha) = 2\alpha - w_p(\alpha) - w_q(\alpha) \geq d(p, q)$ has a
+  /// non-decreasing, piecewise linear left-hand side, so its smallest solution is found by linear
+  /// interpolation between the breakpoints of the weights.
+  fn edge_birth(&self, distance: f64, lambda_p: f64, lambda_q: f64) -> Option<f64> {
+    let epsilon = self.approximation;
+    let weight = |lambda: f64, alpha: f64| {
+      if alpha <= lambda / epsilon {
+        0.0
+      } else if alpha < lambda / (epsilon * (1.0 - epsilon)) {
+        alpha - lambda / epsilon
+      } else {
+        epsilon * alpha
+      }
+    };
+    let g = |alpha: f64| 2.0 * alpha - weight(lambda_p, alpha) - weight(lambda_q, alpha);
+
+    let removal = lambda_p.min(lambda_q) / (epsilon * (1.0 - epsilon));
+    let mut breakpoints: Vec<f64> = [lambda_p, lambda_q]
+      .into_iter()
+      .flat_map(|lambda| [lambda / epsilon, lambda / (epsilon * (1.0 - epsilon))])
+      .filter(|alpha| *alpha < removal)
+      .collect();
+    breakpoints.extend([0.0, removal]);
+    breakpoints.sort_by(f64::total_cmp);
+
+    let mut previous = 0.0;
+    for alpha in breakpoints {
+      if g(alpha) >= distance {
+        let (low, high) = (g(previous), g(alpha));
+        let birth = if high > low {
+          previous + (distance - low).max(0.0) * (alpha - previous) / (high - low)
+        } else {
+          previous
+        };
+        return Some(2.0 * birth);
+      }
+      previous = alpha;
+    }
+    None
+  }
+}
+
+/// Implements the [`Filtration`] trait for `SparseRips` to generate the whole filtration at once.
+impl<const N: usize> Filtration for SparseRips<N> {
+  type InputParameter = Option<f64>;
+  type InputSpace = Cloud<N>;
+  type OutputParameter = ();
+  type OutputSpace = FilteredComplex<Simplex>;
+
+  /// Builds the sparse Rips filtration up to the optional `max_epsilon`.
+  ///
+  /// This method delegates to [`SparseRips::build_filtration`].
+  fn build(
+    &self,
+    cloud: &Self::InputSpace,
+    max_epsilon: Self::InputParameter,
+    _output_param: &(),
+  ) -> Self::OutputSpace {
+    self.build_filtration(cloud, max_epsilon)
+  }
+}
+
+/// Computes a greedy permutation of `cloud` starting from its first point.
+///
+/// Returns the point indices in permutation order, each paired with its insertion radius: the
+/// distance to the points before it, which is infinite for the first point and non-increasing
+/// along the permutation.
+pub fn greedy_permutation<const N: usize>(cloud: &Cloud<N>) -> Vec<(usize, f64)> {
+  let count = cloud.points_ref().len();
+  farthest_point_sampling(cloud, 0, count)
+}
+
+#[cfg(test)]
+mod tests {
+  use cova_algebra::{algebras::boolean::Boolean, tensors::SVector};
+
+  use super::*;
+  use crate::{
+    filtration::vietoris_rips::VietorisRips,
+    persistence::{PersistenceDiagram, bottleneck_distance},
+  };
+
+  fn noisy_circle(count: usize) -> Cloud<2> {
+    Cloud::new(
+      (0..count)
+        .map(|i| {
+          let angle = std::f64::consts::TAU * i as f64 / count as f64;
+          let radius = 1.0 + 0.05 * (7.0 * angle).sin();
+          SVector::from([radius * angle.cos(), radius * angle.sin()])
+        })
+        .collect(),
+    )
+  }
+
+  #[test]
+  fn test_greedy_permutation() {
+    let line = Cloud::new((0..5).map(|i| SVector::from([f64::from(i * i), 0.0])).collect());
+    let permutation = greedy_permutation(&line);
+
+    let indices: Vec<usize> = permutation.iter().map(|(index, _)| *index).collect();
+    assert_eq!(indices, vec![0, 4, 3, 2, 1]);
+    let radii: Vec<f64> = permutation.iter().map(|(_, radius)| *radius).collect();
+    assert_eq!(radii, vec![f64::INFINITY, 16.0, 7.0, 4.0, 1.0]);
+    assert!(radii.windows(2).all(|pair| pair[0] >= pair[1]));
+  }
+
+  #[test]
+  fn test_edge_birth() {
+    let sparse = SparseRips::<2>::new(0.5);
+    // Without weights an edge is born at its length.
+    assert_eq!(sparse.edge_birth(1.0, f64::INFINITY, 10.0), Some(1.0));
+    // With λ_q = 1 the weight of q starts at α = 2 and q is removed at α = 4. An edge of length
+    // 5 needs 2α - (α - 2) ≥ 5, i.e. α = 3, and is born at 6.
+    assert_eq!(sparse.edge_birth(5.0, f64::INFINITY, 1.0), Some(6.0));
+    // An edge of length 7 would need α = 5, after q was removed.
+    assert_eq!(sparse.edge_birth(7.0, f64::INFINITY, 1.0), None);
+  }
+
+  #[test]
+  fn test_sparse_rips_is_smaller_and_monotone() {
+    let cloud = noisy_circle(120);
+    let sparse = SparseRips::<2>::new(0.3).with_max_dimension(2).build_filtration(&cloud, None);
+    let rips_edges = 120 * 119 / 2;
+
+    assert!(sparse.is_monotone());
+    assert_eq!(sparse.complex().elements_of_dimension(0).len(), 120);
+    assert!(sparse.complex().elements_of_dimension(1).len() * 4 < rips_edges);
+    for simplex in sparse.complex().elements.values().filter(|s| s.dimension() == 1) {
+      let [a, b] = simplex.vertices() else { unreachable!() };
+      let distance = Cloud::<2>::distance(cloud.points_ref()[*a], cloud.points_ref()[*b]);
+      assert!(sparse.value(simplex).unwrap() >= distance - 1e-12);
+    }
+  }
+
+  #[test]
+  fn test_sparse_rips_approximates_rips_diagram() {
+    let cloud = noisy_circle(30);
+    let rips = VietorisRips::<2, FilteredComplex<Simplex>>::new()
+      .with_max_dimension(2)
+      .build_filtration(&cloud, None)
+      .persistent_homology::<Boolean>();
+
+    let log_diagram = |diagram: &PersistenceDiagram| {
+      let mut diagram = diagram.clone();
+      for interval in &mut diagram.intervals {
+        interval.birth = interval.birth.max(1e-3).ln();
+        interval.death = interval.death.max(1e-3).ln();
+      }
+      diagram
+    };
+
+    let mut previous = f64::INFINITY;
+    for approximation in [0.5, 0.2, 0.05] {
+      let sparse = SparseRips::<2>::new(approximation)
+        .with_max_dimension(2)
+        .build_filtration(&cloud, None)
+        .persistent_homology::<Boolean>();
+      let distance = bottleneck_distance(&log_diagram(&rips[1]), &log_diagram(&sparse[1]));
+      assert!(distance <= 2.0 * approximation, "ε = {approximation}: distance {distance}");
+      assert!(distance <= previous + 1e-9);
+      previous = distance;
+      assert_eq!(sparse[0].essential().count(), 1);
+    }
+  }
+
+  #[test]
+  #[should_panic(expected = "strictly between 0 and 1")]
+  fn test_invalid_approximation() { SparseRips::<2>::new(1.0); }
+}
@@ -6,18 +6,25 @@
 //! ## Definition
 //!
 //! Given a finite set of points $X = \\{x_1, x_2, \dots, x_n\\}$ in a metric space $(M, d)$
-//! and a real number $\\epsilon > 0$ (the distance threshold), the **Vietoris-Rips complex**
+//! and a real number $\\epsilon > 0$ (the ball radius), the **Vietoris-Rips complex**
 //! $VR_\\epsilon(X)$ is an abstract simplicial complex whose vertices are the points in $X$.
 //! A $k$-simplex $[x_{i_0}, x_{i_1}, \dots, x_{i_k}]$ is included in $VR_\\epsilon(X)$ if and
-//! only if the distance between any pair of its vertices is less than or equal to $\\epsilon$.
-//! That is:
+//! only if the open balls of radius $\\epsilon$ around any pair of its vertices intersect, i.e.
+//! the distance between any pair of its vertices is less than $2\\epsilon$. That is:
 //! $$
-//! [x_{i_0}, x_{i_1}, \dots, x_{i_k}] \\in VR_\\epsilon(X) \\iff d(x_{i_j}, x_{i_l}) \\le
-//! \\epsilon \\quad \\forall j, l \\in \\{0, 1, \dots, k\\}
+//! [x_{i_0}, x_{i_1}, \dots, x_{i_k}] \\in VR_\\epsilon(X) \\iff d(x_{i_j}, x_{i_l}) <
+//! 2\\epsilon \\quad \\forall j, l \\in \\{0, 1, \dots, k\\}
 //! $$
 //!
 //! In simpler terms, a set of points forms a simplex if all points in that set are pairwise
-//! within distance $\\epsilon$ of each other.
+//! closer than $2\\epsilon$ to each other.
+//!
+//! Note that pairwise intersection of the balls does not imply that all of them share a common
+//! point: the **Čech complex**, in which a simplex appears only once the balls around all of its
+//! vertices intersect, is built by [`Cech`](super::cech::Cech). The two complexes have the same
+//! 1-skeleton and satisfy $\\check{C}_\\epsilon(X) \\subseteq VR_\\epsilon(X) \\subseteq
+//! \\check{C}_{2\\epsilon}(X)$, but only the Čech complex is the nerve of the balls and carries
+//! the homotopy type of their union.
 //!
 //! ## Filtration
 //!
@@ -63,6 +70,10 @@
 //! assert_eq!(complex.elements_of_dimension(2).len(), 1); // 1 triangle (2-simplex)
 //! ```
 //!
+//! To study all scales at once, use `VietorisRips<N, FilteredComplex<Simplex>>` instead: its
+//! [`VietorisRips::build_filtration`] produces a single [`FilteredComplex`] in which each simplex
+//! is born at its diameter, ready for persistent homology.
+//!
 //! When the `"parallel"` feature is enabled, this module also provides implementations for
 //! `ParallelFiltration` to leverage multi-core processing
 //! for building the filtration and computing homology.
@@ -73,14 +84,13 @@ use std::{
 };
 
 use cova_algebra::rings::Field;
-use itertools::Itertools;
 
 #[cfg(feature = "parallel")]
 use crate::filtration::ParallelFiltration;
 use crate::{
   cloud::Cloud,
-  complexes::{Complex, Simplex, SimplicialComplex},
-  filtration::Filtration,
+  complexes::{Complex, FilteredComplex, Simplex, SimplicialComplex},
+  filtration::{Filtration, filtered_simplicial_complex},
   homology::Homology,
   prelude::MetricSpace,
 };
@@ -91,8 +101,7 @@ use crate::{
 /// threshold `epsilon` to produce a [`SimplicialComplex`] or [`Homology`]s.
 ///
 /// A $k$-simplex `[v0, v1, ..., vk]` is included in the Vietoris-Rips complex if
-/// the distance between any pair of its vertices `(vi, vj)` is less than or equal
-/// to the threshold `epsilon`.
+/// the distance between any pair of its vertices `(vi, vj)` is less than `2 * epsilon`.
 ///
 /// # Type Parameters
 ///
@@ -100,7 +109,35 @@ use crate::{
 /// * `F`: The numeric type for coordinates and distances (must be a [`Field`]).
 /// * `O`: The output type of the filtration. This is typically [`SimplicialComplex`] or
 ///   [`HashMap<usize, Homology<R>>`](HashMap) if computing homology directly.
+///
+/// # Maximum Dimension
+///
+/// By default simplices of every dimension are built, which for dense point clouds means
+/// enumerating very large cliques. Most applications only need the skeleton up to dimension 2 or
+/// 3, which can be requested with [`VietorisRips::with_max_dimension`]:
+///
+/// ```rust
+/// use cova_algebra::tensors::SVector;
+/// use cova_space::{
+///   cloud::Cloud, complexes::SimplicialComplex, filtration::vietoris_rips::VietorisRips,
+/// };
+///
+/// // Four mutually close points span a 3-simplex.
+/// let cloud: Cloud<2> = Cloud::new(vec![
+///   SVector::from([0.0, 0.0]),
+///   SVector::from([1.0, 0.0]),
+///   SVector::from([0.0, 1.0]),
+///   SVector::from([1.0, 1.0]),
+/// ]);
+///
+/// let vr = VietorisRips::<2, SimplicialComplex>::new().with_max_dimension(1);
+/// let complex = vr.build_complex(&cloud, 1.0);
+///
+/// assert_eq!(complex.elements_of_dimension(1).len(), 6);
+/// assert!(complex.elements_of_dimension(2).is_empty());
+/// ```
 pub struct VietorisRips<const N: usize, O> {
+  max_dimension: Option<usize>,
   _phantom:      PhantomData<[f64; N]>, // To use N and F generics
   _output_space: PhantomData<O>,        // To specialize filtration output
 }
@@ -110,7 +147,21 @@ impl<const N: usize, O> VietorisRips<N, O> {
   ///
   /// The specific behavior of the builder (e.g., what it produces) is determined
   /// by the trait implementations for `Filtration` based on the type parameter `O`.
-  pub const fn new() -> Self { Self { _phantom: PhantomData, _output_space: PhantomData } }
+  pub const fn new() -> Self {
+    Self { max_dimension: None, _phantom: PhantomData, _output_space: PhantomData }
+  }
+
+  /// Limits the constructed complexes to simplices of dimension at most `max_dimension`.
+  ///
+  /// Homology in dimension $k$ depends on the $(k+1)$-skeleton, so computing $H_k$ requires
+  /// `max_dimension >= k + 1`.
+  pub const fn with_max_dimension(mut self, max_dimension: usize) -> Self {
+    self.max_dimension = Some(max_dimension);
+    self
+  }
+
+  /// Returns the maximum simplex dimension, or `None` if the dimension is unbounded.
+  pub const fn max_dimension(&self) -> Option<usize> { self.max_dimension }
 }
 
 impl<const N: usize> VietorisRips<N, Complex<Simplex>> {
@@ -120,8 +171,8 @@ impl<const N: usize> VietorisRips<N, Complex<Simplex>> {
   /// # Arguments
   ///
   /// * `cloud`: A reference to a [`Cloud<N, F>`] containing the input points.
-  /// * `epsilon`: The distance threshold $F$. A simplex is formed by a set of points if all
-  ///   pairwise distances within that set are less than or equal to `epsilon`.
+  /// * `epsilon`: The ball radius. A simplex is formed by a set of points if all pairwise distances
+  ///   within that set are less than `2 * epsilon`.
   ///
   /// # Returns
   ///
@@ -130,47 +181,157 @@ impl<const N: usize> VietorisRips<N, Complex<Simplex>> {
   ///
   /// # Details
   ///
-  /// 1. All points in the `cloud` are added as 0-simplices (vertices).
-  /// 2. For $k > 0$, a $k$-simplex is formed by $k+1$ vertices if all pairwise distances between
-  ///    these vertices are $\\le \\epsilon$.
-  /// 3. The method iterates through all combinations of $k+1$ points for increasing $k$ (from up to
-  ///    number of points minus 1).
-  /// 4. Distances are typically squared Euclidean distances for efficiency, so `epsilon` is squared
-  ///    for comparison.
+  /// 1. The neighborhood graph is built once, joining $x_i$ and $x_j$ by an edge whenever $d(x_i,
+  ///    x_j) < 2\\epsilon$.
+  /// 2. All points in the `cloud` are added as 0-simplices (vertices).
+  /// 3. The cliques of the neighborhood graph are enumerated by incremental expansion: each clique
+  ///    is only extended by common neighbors with a larger index, so every simplex is visited
+  ///    exactly once and the work is proportional to the size of the output.
+  /// 4. Expansion stops at [`VietorisRips::max_dimension`], if one is set.
   pub fn build_complex(&self, cloud: &Cloud<N>, epsilon: f64) -> SimplicialComplex {
-    let mut complex = SimplicialComplex::new();
-    let points_vec = cloud.points_ref();
+    clique_complex(cloud, epsilon, self.max_dimension)
+  }
+}
 
-    if points_vec.is_empty() {
-      return complex;
-    }
+/// Builds the clique complex of the neighborhood graph of `cloud` at threshold `2 * epsilon`.
+fn clique_complex<const N: usize>(
+  cloud: &Cloud<N>,
+  epsilon: f64,
+  max_dimension: Option<usize>,
+) -> SimplicialComplex {
+  let mut complex = SimplicialComplex::new();
+  let graph = neighborhood_graph(cloud, 2.0 * epsilon);
+  for_each_clique(&graph, max_dimension.unwrap_or(usize::MAX), |clique| {
+    complex.join_element(Simplex::new(clique.len() - 1, clique.to_vec()));
+    true
+  });
+  complex
+}
 
-    for i in 0..points_vec.len() {
-      complex.join_element(Simplex::new(0, vec![i]));
-    }
+/// Returns, for each point, the sorted indices of the later points closer than `threshold`.
+pub(super) fn neighborhood_graph<const N: usize>(
+  cloud: &Cloud<N>,
+  threshold: f64,
+) -> Vec<Vec<usize>> {
+  let points = cloud.points_ref();
+  (0..points.len())
+    .map(|i| {
+      (i + 1..points.len())
+        .filter(|&j| Cloud::<N>::distance(points[i], points[j]) < threshold)
+        .collect()
+    })
+    .collect()
+}
 
-    for dim_k in 1..points_vec.len() {
-      for vertex_indices in (0..points_vec.len()).combinations(dim_k + 1) {
-        let mut form_simplex = true;
-        for edge_node_indices in vertex_indices.iter().copied().combinations(2) {
-          let p1_idx = edge_node_indices[0];
-          let p2_idx = edge_node_indices[1];
-
-          let point_a = points_vec[p1_idx];
-          let point_b = points_vec[p2_idx];
-
-          if Cloud::<N>::distance(point_a, point_b) >= 2.0 * epsilon {
-            form_simplex = false;
-            break;
-          }
-        }
-
-        if form_simplex {
-          complex.join_element(Simplex::new(dim_k, vertex_indices));
-        }
-      }
-    }
-    complex
+/// Calls `visit` on every clique of `graph` with at most `max_dimension + 1` vertices.
+///
+/// `graph[v]` must list the neighbors of `v` with a larger index, in increasing order. Cliques are
+/// reported as increasing vertex lists, each before any of its extensions. A clique is only
+/// extended further if `visit` returns `true` for it.
+pub(super) fn for_each_clique(
+  graph: &[Vec<usize>],
+  max_dimension: usize,
+  mut visit: impl FnMut(&[usize]) -> bool,
+) {
+  let mut clique = Vec::new();
+  for vertex in 0..graph.len() {
+    clique.push(vertex);
+    expand_clique(graph, &mut clique, &graph[vertex], max_dimension, &mut visit);
+    clique.pop();
+  }
+}
+
+/// Reports `clique` and recursively extends it by each of the common neighbors in `candidates`.
+fn expand_clique(
+  graph: &[Vec<usize>],
+  clique: &mut Vec<usize>,
+  candidates: &[usize],
+  max_dimension: usize,
+  visit: &mut impl FnMut(&[usize]) -> bool,
+) {
+  if !visit(clique) || clique.len() > max_dimension {
+    return;
+  }
+  for (i, &vertex) in candidates.iter().enumerate() {
+    let next_candidates: Vec<usize> = candidates[i + 1..]
+      .iter()
+      .copied()
+      .filter(|other| graph[vertex].binary_search(other).is_ok())
+      .collect();
+    clique.push(vertex);
+    expand_clique(graph, clique, &next_candidates, max_dimension, visit);
+    clique.pop();
+  }
+}
+
+impl<const N: usize> VietorisRips<N, FilteredComplex<Simplex>> {
+  /// Builds the whole Vietoris-Rips filtration of `cloud` as a single [`FilteredComplex`].
+  ///
+  /// Every simplex carries its **diameter** (the largest pairwise distance between its vertices)
+  /// as its filtration value, so the sublevel set at value $t$ contains exactly the simplices
+  /// whose vertices are pairwise within distance $t$. Since
+  /// [`VietorisRips::build_complex`] joins points closer than $2\epsilon$, the complex built at
+  /// `epsilon` consists of the simplices with diameter strictly below `2.0 * epsilon`, which
+  /// agrees with [`FilteredComplex::truncate`] at `2.0 * epsilon` unless some diameter equals
+  /// that value exactly.
+  ///
+  /// # Arguments
+  ///
+  /// * `cloud`: The input points.
+  /// * `max_epsilon`: If given, only simplices that appear in the complex at `max_epsilon` are
+  ///   kept, i.e. those with diameter strictly below `2.0 * max_epsilon`.
+  ///
+  /// Simplices are also limited by [`VietorisRips::max_dimension`], if set.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use cova_algebra::{algebras::boolean::Boolean, tensors::SVector};
+  /// use cova_space::{
+  ///   cloud::Cloud,
+  ///   complexes::{FilteredComplex, Simplex},
+  ///   filtration::vietoris_rips::VietorisRips,
+  /// };
+  ///
+  /// // Four corners of a unit square: edges appear at 1, diagonals at √2.
+  /// let cloud: Cloud<2> = Cloud::new(vec![
+  ///   SVector::from([0.0, 0.0]),
+  ///   SVector::from([1.0, 0.0]),
+  ///   SVector::from([1.0, 1.0]),
+  ///   SVector::from([0.0, 1.0]),
+  /// ]);
+  ///
+  /// let vr = VietorisRips::<2, FilteredComplex<Simplex>>::new().with_max_dimension(2);
+  /// let filtered = vr.build_filtration(&cloud, None);
+  ///
+  /// // The square loop is born at 1 and filled in at √2.
+  /// let diagrams = filtered.persistent_homology::<Boolean>();
+  /// let loop_interval = diagrams[1].intervals[0];
+  /// assert_eq!(loop_interval.birth, 1.0);
+  /// assert!((loop_interval.death - 2.0_f64.sqrt()).abs() < 1e-12);
+  /// ```
+  pub fn build_filtration(
+    &self,
+    cloud: &Cloud<N>,
+    max_epsilon: Option<f64>,
+  ) -> FilteredComplex<Simplex> {
+    let points = cloud.points_ref();
+    let threshold = max_epsilon.map_or(f64::INFINITY, |epsilon| 2.0 * epsilon);
+    let graph = neighborhood_graph(cloud, threshold);
+
+    let mut simplices = Vec::new();
+    for_each_clique(&graph, self.max_dimension.unwrap_or(usize::MAX), |clique| {
+      let diameter = clique
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &a)| clique[i + 1..].iter().map(move |&b| (a, b)))
+        .map(|(a, b)| Cloud::<N>::distance(points[a], points[b]))
+        .fold(0.0, f64::max);
+      simplices.push((clique.to_vec(), diameter));
+      true
+    });
+
+    filtered_simplicial_complex(simplices)
   }
 }
 
@@ -206,6 +367,36 @@ impl<const N: usize> Filtration for VietorisRips<N, Complex<Simplex>> {
   }
 }
 
+/// Provides a default constructor for `VietorisRips` when the output is a [`FilteredComplex`].
+impl<const N: usize> Default for VietorisRips<N, FilteredComplex<Simplex>> {
+  fn default() -> Self { Self::new() }
+}
+
+/// Implements the [`Filtration`] trait for `VietorisRips` to generate the whole filtration at once.
+///
+/// Instead of building one complex per `epsilon`, a single [`FilteredComplex`] records the
+/// diameter of every simplex, from which the complex at any scale can be recovered with
+/// [`FilteredComplex::truncate`] and which can be passed directly to
+/// [`FilteredComplex::persistent_homology`].
+impl<const N: usize> Filtration for VietorisRips<N, FilteredComplex<Simplex>> {
+  type InputParameter = Option<f64>;
+  type InputSpace = Cloud<N>;
+  type OutputParameter = ();
+  type OutputSpace = FilteredComplex<Simplex>;
+
+  /// Builds the Vietoris-Rips filtration up to the optional `max_epsilon`.
+  ///
+  /// This method delegates to [`VietorisRips::build_filtration`].
+  fn build(
+    &self,
+    cloud: &Self::InputSpace,
+    max_epsilon: Self::InputParameter,
+    _output_param: &(),
+  ) -> Self::OutputSpace {
+    self.build_filtration(cloud, max_epsilon)
+  }
+}
+
 /// Implements [`ParallelFiltration`] for `VietorisRips` targeting [`SimplicialComplex`] output.
 ///
 /// This implementation is active when the `"parallel"` feature is enabled.
@@ -250,8 +441,7 @@ impl<const N: usize, R: Field + Copy> Filtration for VietorisRips<N, Homology<R>
     param: Self::InputParameter,          // epsilon
     output_param: &Self::OutputParameter, // dimensions for homology
   ) -> Self::OutputSpace {
-    let complex_builder = VietorisRips::<N, Complex<Simplex>>::new();
-    let complex = complex_builder.build_complex(input, param);
+    let complex = clique_complex(input, param, self.max_dimension);
 
     let mut homology_groups = HashMap::new();
     // For each dimension requested in output_param, compute homology.
@@ -277,7 +467,7 @@ impl<const N: usize, R> ParallelFiltration for VietorisRips<N, Homology<R>> wher
 #[cfg(test)]
 mod tests {
   // For homology coefficients
-  use cova_algebra::{algebras::boolean::Boolean, modular, prime_field, tensors::SVector};
+  use cova_algebra::{modular, prime_field, tensors::SVector};
 
   use super::*;
 
@@ -347,6 +537,120 @@ mod tests {
     assert_eq!(complex.elements_of_dimension(2)[0].vertices(), &[0, 1, 2]);
   }
 
+  #[test]
+  fn test_vietoris_rips_max_dimension() {
+    // Six points on a small circle are pairwise close, so they span a full 5-simplex.
+    let points = (0..6)
+      .map(|i| {
+        let theta = std::f64::consts::TAU * f64::from(i) / 6.0;
+        SVector::from([theta.cos(), theta.sin()])
+      })
+      .collect();
+    let cloud = Cloud::new(points);
+
+    let full = VietorisRips::<2, SimplicialComplex>::new().build_complex(&cloud, 1.5);
+    assert_eq!(full.elements_of_dimension(5).len(), 1);
+
+    let capped =
+      VietorisRips::<2, SimplicialComplex>::new().with_max_dimension(2).build_complex(&cloud, 1.5);
+    assert_eq!(capped.elements_of_dimension(1).len(), 15);
+    assert_eq!(capped.elements_of_dimension(2).len(), 20);
+    assert!(capped.elements_of_dimension(3).is_empty());
+    assert_eq!(capped.max_dimension(), 2);
+  }
+
+  #[test]
+  fn test_vietoris_rips_matches_exhaustive_search() {
+    use itertools::Itertools;
+
+    // A slightly irregular grid, so that different thresholds give different complexes.
+    let points: Vec<SVector<f64, 2>> = (0..12)
+      .map(|i| {
+        let (x, y) = (f64::from(i % 4), f64::from(i / 4));
+        SVector::from([x + 0.1 * (y * 1.7).sin(), y + 0.1 * (x * 2.3).cos()])
+      })
+      .collect();
+    let cloud = Cloud::new(points.clone());
+    let epsilon = 0.75;
+
+    let complex = VietorisRips::<2, SimplicialComplex>::new().build_complex(&cloud, epsilon);
+
+    for dimension in 0..4 {
+      let mut expected: Vec<Vec<usize>> = (0..points.len())
+        .combinations(dimension + 1)
+        .filter(|vertices| {
+          vertices
+            .iter()
+            .tuple_combinations()
+            .all(|(&a, &b)| Cloud::<2>::distance(points[a], points[b]) < 2.0 * epsilon)
+        })
+        .collect();
+      expected.sort();
+      let mut actual: Vec<Vec<usize>> = complex
+        .elements_of_dimension(dimension)
+        .iter()
+        .map(|simplex| simplex.vertices().to_vec())
+        .collect();
+      actual.sort();
+      assert_eq!(actual, expected, "dimension {dimension}");
+    }
+  }
+
+  #[test]
+  fn test_vietoris_rips_filtration_matches_complexes() {
+    let points: Vec<SVector<f64, 2>> = (0..10)
+      .map(|i| {
+        let theta = std::f64::consts::TAU * f64::from(i) / 10.0;
+        SVector::from([theta.cos() * (1.0 + 0.05 * f64::from(i % 3)), theta.sin()])
+      })
+      .collect();
+    let cloud = Cloud::new(points);
+
+    let filtered = VietorisRips::<2, FilteredComplex<Simplex>>::new()
+      .with_max_dimension(2)
+      .build_filtration(&cloud, None);
+    assert!(filtered.is_monotone());
+
+    let builder = VietorisRips::<2, SimplicialComplex>::new().with_max_dimension(2);
+    for epsilon in [0.1, 0.35, 0.6, 0.9, 1.2] {
+      let complex = builder.build_complex(&cloud, epsilon);
+      let truncated = filtered.truncate(2.0 * epsilon);
+      for dimension in 0..=2 {
+        let vertex_lists = |simplices: Vec<Simplex>| {
+          let mut lists: Vec<Vec<usize>> =
+            simplices.iter().map(|simplex| simplex.vertices().to_vec()).collect();
+          lists.sort();
+          lists
+        };
+        let expected = vertex_lists(complex.elements_of_dimension(dimension));
+        let actual = vertex_lists(truncated.elements_of_dimension(dimension));
+        assert_eq!(actual, expected, "epsilon {epsilon}, dimension {dimension}");
+      }
+    }
+  }
+
+  #[test]
+  fn test_vietoris_rips_filtration_max_epsilon() {
+    let cloud = Cloud::new(vec![
+      SVector::from([0.0, 0.0]),
+      SVector::from([1.0, 0.0]),
+      SVector::from([3.0, 0.0]),
+    ]);
+    let vr = VietorisRips::<2, FilteredComplex<Simplex>>::new();
+
+    let full = vr.build(&cloud, None, &());
+    assert_eq!(full.complex().elements_of_dimension(2).len(), 1);
+    let triangle = full.complex().elements_of_dimension(2)[0].clone();
+    assert_eq!(full.value(&triangle), Some(3.0));
+
+    // Only the edge of length 1 is shorter than 2 * 0.75.
+    let partial = vr.build(&cloud, Some(0.75), &());
+    assert_eq!(partial.complex().elements_of_dimension(0).len(), 3);
+    assert_eq!(partial.complex().elements_of_dimension(1).len(), 1);
+    let diagrams = partial.persistent_homology::<Mod7>();
+    assert_eq!(diagrams[0].essential().count(), 2);
+  }
+
   modular!(Mod7, u32, 7);
   prime_field!(Mod7);
 
@@ -404,6 +708,8 @@ mod tests {
   fn test_compute_homology_filtration_parallel_triangle() {
     // This test runs only if 'parallel' feature is enabled.
     // It implicitly uses build_parallel from the ParallelFiltration trait.
+    use cova_algebra::algebras::boolean::Boolean;
+
     use crate::filtration::ParallelFiltration; // Make sure trait is in scope
 
     let p0 = SVector::from([0.0, 0.0]);
@@ -0,0 +1,411 @@
+//! # Witness Complexes
+//!
+//! This module builds **lazy witness complexes**, which summarize the shape of a large point
+//! cloud with a complex on a small set of landmark points. The size of the complex depends only
+//! on the number of landmarks, while every point of the cloud contributes as a witness.
+//!
+//! ## Mathematical Background
+//!
+//! Let $L \subseteq X$ be a set of **landmarks** chosen from a point cloud $X$, and treat every
+//! point $w \in X$ as a **witness**. For a parameter $\nu \geq 0$ let $m_\nu(w)$ be the distance
+//! from $w$ to its $\nu$-th closest landmark, with $m_0(w) = 0$. Following de Silva and Carlsson,
+//! an edge $[a, b]$ between landmarks is witnessed at scale $R \geq 0$ if some witness satisfies
+//! $$ \max\big(d(a, w), d(b, w)\big) \leq R + m_\nu(w). $$
+//! The **lazy witness complex** $LW_\nu(L, X; R)$ is the flag complex of these edges: a set of
+//! landmarks spans a simplex as soon as all of its edges are witnessed. Every simplex therefore
+//! enters the filtration at
+//! $$ f(\sigma) = \max_{a, b \in \sigma} \; \min_{w \in X} \Big( \max\big(d(a, w), d(b, w)\big) -
+//! m_\nu(w) \Big)^+, $$
+//! with $f = 0$ on the landmarks themselves.
+//!
+//! With $\nu = 0$ an edge needs a witness within $R$ of both endpoints; larger $\nu$ make edges
+//! appear earlier in regions that are densely covered by landmarks, and $\nu = 1$ or $\nu = 2$
+//! are the usual choices.
+//!
+//! ## Landmark Selection
+//!
+//! [`LandmarkSelection`] offers two strategies:
+//! - **Random**: a uniformly random subset, reproducible from a seed.
+//! - **Max-min** (farthest point sampling): starting from one point, repeatedly add the point
+//!   farthest from all landmarks chosen so far. This spreads the landmarks evenly over the cloud
+//!   and is usually the better choice.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use cova_algebra::{algebras::boolean::Boolean, tensors::SVector};
+//! use cova_space::{
+//!   cloud::Cloud,
+//!   filtration::witness::{LandmarkSelection, LazyWitness},
+//! };
+//!
+//! // A densely sampled circle, summarised by 12 landmarks.
+//! let points = (0..500)
+//!   .map(|i| {
+//!     let angle = std::f64::consts::TAU * f64::from(i) / 500.0;
+//!     SVector::from([angle.cos(), angle.sin()])
+//!   })
+//!   .collect();
+//! let cloud: Cloud<2> = Cloud::new(points);
+//!
+//! let witness =
+//!   LazyWitness::new(LandmarkSelection::MaxMin { count: 12, start: 0 }).with_max_dimension(2);
+//! let filtered = witness.build_filtration(&cloud, None);
+//! assert_eq!(filtered.complex().elements_of_dimension(0).len(), 12);
+//!
+//! let complex = filtered.truncate(0.1);
+//! assert_eq!(complex.homology::<Boolean>(1).betti_number, 1);
+//! ```
+
+use cova_algebra::tensors::SVector;
+
+use crate::{
+  cloud::Cloud,
+  complexes::{FilteredComplex, Simplex},
+  definitions::MetricSpace,
+  filtration::{Filtration, filtered_simplicial_complex, vietoris_rips::for_each_clique},
+};
+
+/// A strategy for choosing landmarks from a point cloud.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LandmarkSelection {
+  /// A uniformly random subset of `count` points, determined by `seed`.
+  Random {
+    /// The number of landmarks.
+    count: usize,
+    /// The seed of the pseudo-random generator, so that the selection is reproducible.
+    seed:  u64,
+  },
+  /// Max-min (farthest point) sampling of `count` points, starting from the point with index
+  /// `start`.
+  MaxMin {
+    /// The number of landmarks.
+    count: usize,
+    /// The index of the first landmark.
+    start: usize,
+  },
+}
+
+impl LandmarkSelection {
+  /// Chooses landmarks from `cloud`, returning their indices in the order they were selected.
+  ///
+  /// # Panics
+  ///
+  /// Panics if more landmarks are requested than the cloud has points, or if the `start` index
+  /// of [`LandmarkSelection::MaxMin`] is out of bounds.
+  pub fn select<const N: usize>(&self, cloud: &Cloud<N>) -> Vec<usize> {
+    let points = cloud.points_ref();
+    match *self {
+      Self::Random { count, seed } => {
+        assert!(count <= points.len(), "cannot select more landmarks than there are points");
+        // A partial Fisher-Yates shuffle driven by SplitMix64.
+        let mut state = seed;
+        let mut indices: Vec<usize> = (0..points.len()).collect();
+        for i in 0..count {
+          state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+          let mut z = state;
+          z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+          z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+          z ^= z >> 31;
+          let j = i + (z % (points.len() - i) as u64) as usize;
+          indices.swap(i, j);
+        }
+        indices.truncate(count);
+        indices
+      },
+      Self::MaxMin { count, start } => {
+        assert!(count <= points.len(), "cannot select more landmarks than there are points");
+        farthest_point_sampling(cloud, start, count).into_iter().map(|(index, _)| index).collect()
+      },
+    }
+  }
+}
+
+/// Selects `count` points of `cloud` by farthest point sampling, starting from `start`.
+///
+/// Returns each selected index together with its **insertion radius**, the distance to the points
+/// selected before it, which is infinite for the first point. Ties are broken towards the lower
+/// index.
+///
+/// # Panics
+///
+/// Panics if `count > 0` and `start` is out of bounds.
+pub(super) fn farthest_point_sampling<const N: usize>(
+  cloud: &Cloud<N>,
+  start: usize,
+  count: usize,
+) -> Vec<(usize, f64)> {
+  let points = cloud.points_ref();
+  if count == 0 {
+    return Vec::new();
+  }
+  assert!(start < points.len(), "the first landmark must be a point of the cloud");
+  let mut selected = vec![(start, f64::INFINITY)];
+  // Selected points are marked with a negative distance so that they are never selected again,
+  // while duplicates of them can still be selected at insertion radius zero.
+  let mut distance: Vec<f64> =
+    points.iter().map(|&point| Cloud::<N>::distance(point, points[start])).collect();
+  distance[start] = f64::NEG_INFINITY;
+  while selected.len() < count.min(points.len()) {
+    let farthest = (0..points.len())
+      .max_by(|&a, &b| distance[a].total_cmp(&distance[b]).then(b.cmp(&a)))
+      .expect("the cloud is not empty");
+    selected.push((farthest, distance[farthest]));
+    distance[farthest] = f64::NEG_INFINITY;
+    for (point, nearest) in points.iter().zip(&mut distance) {
+      *nearest = nearest.min(Cloud::<N>::distance(*point, points[farthest]));
+    }
+  }
+  selected
+}
+
+/// A builder for lazy witness complex filtrations.
+///
+/// It implements the [`Filtration`] trait, taking a [`Cloud`] and an optional maximal scale to
+/// produce a [`FilteredComplex`] on the landmarks. Vertices are labelled by the index of the
+/// landmark in the cloud, and all points of the cloud, including the landmarks, act as
+/// witnesses.
+///
+/// By default the dimension of simplices is unbounded. Without a maximal scale every pair of
+/// landmarks is eventually witnessed, so the filtration then contains all $2^L - 1$ simplices on
+/// $L$ landmarks; use [`LazyWitness::with_max_dimension`] for anything but a handful of landmarks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LazyWitness<const N: usize> {
+  landmarks:     LandmarkSelection,
+  nu:            usize,
+  max_dimension: Option<usize>,
+}
+
+impl<const N: usize> LazyWitness<N> {
+  /// Creates a new lazy witness builder with the given landmark selection and $\nu = 2$.
+  pub const fn new(landmarks: LandmarkSelection) -> Self {
+    Self { landmarks, nu: 2, max_dimension: None }
+  }
+
+  /// Sets the parameter $\nu$, the rank of the landmark whose distance each witness may use as
+  /// slack. See the [module documentation](self) for details.
+  pub const fn with_nu(mut self, nu: usize) -> Self {
+    self.nu = nu;
+    self
+  }
+
+  /// Limits the constructed complexes to simplices of dimension at most `max_dimension`.
+  pub const fn with_max_dimension(mut self, max_dimension: usize) -> Self {
+    self.max_dimension = Some(max_dimension);
+    self
+  }
+
+  /// Returns the parameter $\nu$.
+  pub const fn nu(&self) -> usize { self.nu }
+
+  /// Returns the maximum simplex dimension, or `None` if the dimension is unbounded.
+  pub const fn max_dimension(&self) -> Option<usize> { self.max_dimension }
+
+  /// Builds the lazy witness filtration of `cloud` as a single [`FilteredComplex`].
+  ///
+  /// Every simplex on the landmarks carries the smallest scale $R$ at which all of its edges are
+  /// witnessed, so that [`FilteredComplex::truncate`] at $R$ yields $LW_\nu(L, X; R)$.
+  ///
+  /// # Arguments
+  ///
+  /// * `cloud`: The input points, all of which act as witnesses.
+  /// * `max_scale`: If given, only simplices with value at most `max_scale` are kept.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the landmark selection panics, or if $\nu$ exceeds the number of landmarks.
+  pub fn build_filtration(
+    &self,
+    cloud: &Cloud<N>,
+    max_scale: Option<f64>,
+  ) -> FilteredComplex<Simplex> {
+    let points = cloud.points_ref();
+    let landmarks = self.landmarks.select(cloud);
+    let count = landmarks.len();
+    assert!(self.nu <= count, "nu must not exceed the number of landmarks");
+    let max_scale = max_scale.unwrap_or(f64::INFINITY);
+
+    // edge_values[a * count + b] is the scale at which landmarks a and b are first witnessed.
+    let mut edge_values = vec![f64::INFINITY; count * count];
+    let mut nearest: Vec<(f64, usize)> = Vec::with_capacity(count);
+    for witness in points {
+      nearest.clear();
+      nearest.extend(
+        landmarks.iter().enumerate().map(|(i, &l)| (Cloud::<N>::distance(*witness, points[l]), i)),
+      );
+      nearest.sort_unstable_by(|a, b| a.0.total_cmp(&b.0));
+      let slack = if self.nu == 0 { 0.0 } else { nearest[self.nu - 1].0 };
+
+      // Pairs are witnessed at the distance of their farther landmark, so only landmarks within
+      // `max_scale + slack` of the witness matter.
+      for (j, &(far, b)) in nearest.iter().enumerate() {
+        let value = (far - slack).max(0.0);
+        if value > max_scale {
+          break;
+        }
+        for &(_, a) in &nearest[..j] {
+          let (a, b) = (a.min(b), a.max(b));
+          let entry = &mut edge_values[a * count + b];
+          *entry = entry.min(value);
+        }
+      }
+    }
+
+    // Cliques are enumerated on landmark positions and relabelled by cloud index afterwards.
+    let graph: Vec<Vec<usize>> = (0..count)
+      .map(|a| (a + 1..count).filter(|&b| edge_values[a * count + b] <= max_scale).collect())
+      .collect();
+    let mut simplices = Vec::new();
+    for_each_clique(&graph, self.max_dimension.unwrap_or(usize::MAX), |clique| {
+      let value = clique
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &a)| clique[i + 1..].iter().map(move |&b| (a, b)))
+        .map(|(a, b)| edge_values[a * count + b])
+        .fold(0.0, f64::max);
+      let mut vertices: Vec<usize> = clique.iter().map(|&i| landmarks[i]).collect();
+      vertices.sort_unstable();
+      simplices.push((vertices, value));
+      true
+    });
+
+    filtered_simplicial_complex(simplices)
+  }
+}
+
+/// Implements the [`Filtration`] trait for `LazyWitness` to generate the whole filtration at
+/// once.
+impl<const N: usize> Filtration for LazyWitness<N> {
+  type InputParameter = Option<f64>;
+  type InputSpace = Cloud<N>;
+  type OutputParameter = ();
+  type OutputSpace = FilteredComplex<Simplex>;
+
+  /// Builds the lazy witness filtration up to the optional `max_scale`.
+  ///
+  /// This method delegates to [`LazyWitness::build_filtration`].
+  fn build(
+    &self,
+    cloud: &Self::InputSpace,
+    max_scale: Self::InputParameter,
+    _output_param: &(),
+  ) -> Self::OutputSpace {
+    self.build_filtration(cloud, max_scale)
+  }
+}
+
+/// Returns the points of `cloud` at the given indices, e.g. the landmarks chosen by a
+/// [`LandmarkSelection`].
+pub fn landmark_cloud<const N: usize>(cloud: &Cloud<N>, landmarks: &[usize]) -> Cloud<N> {
+  let points: Vec<SVector<f64, N>> = landmarks.iter().map(|&i| cloud.points_ref()[i]).collect();
+  Cloud::new(points)
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashSet;
+
+  use cova_algebra::algebras::boolean::Boolean;
+
+  use super::*;
+
+  fn circle(count: usize, radius: f64) -> Vec<SVector<f64, 2>> {
+    (0..count)
+      .map(|i| {
+        let angle = std::f64::consts::TAU * i as f64 / count as f64;
+        SVector::from([radius * angle.cos(), radius * angle.sin()])
+      })
+      .collect()
+  }
+
+  #[test]
+  fn test_landmark_selection() {
+    let line = Cloud::new((0..10).map(|i| SVector::from([f64::from(i), 0.0])).collect());
+
+    let maxmin = LandmarkSelection::MaxMin { count: 3, start: 0 }.select(&line);
+    assert_eq!(maxmin[..2], [0, 9]);
+    assert!([4, 5].contains(&maxmin[2]));
+
+    let random = LandmarkSelection::Random { count: 6, seed: 7 };
+    let chosen = random.select(&line);
+    assert_eq!(chosen.len(), 6);
+    assert_eq!(chosen.iter().collect::<HashSet<_>>().len(), 6);
+    assert!(chosen.iter().all(|&i| i < 10));
+    assert_eq!(chosen, random.select(&line));
+    assert_ne!(chosen, LandmarkSelection::Random { count: 6, seed: 8 }.select(&line));
+
+    let landmarks = landmark_cloud(&line, &maxmin);
+    assert_eq!(landmarks.points_ref()[1], SVector::from([9.0, 0.0]));
+  }
+
+  #[test]
+  fn test_maxmin_with_duplicate_points() {
+    let cloud = Cloud::new(vec![
+      SVector::from([0.0, 0.0]),
+      SVector::from([0.0, 0.0]),
+      SVector::from([1.0, 0.0]),
+    ]);
+    let selection = LandmarkSelection::MaxMin { count: 3, start: 0 };
+    assert_eq!(selection.select(&cloud), vec![0, 2, 1]);
+
+    let filtered = LazyWitness::new(selection).build_filtration(&cloud, None);
+    assert_eq!(filtered.complex().elements_of_dimension(0).len(), 3);
+  }
+
+  #[test]
+  #[should_panic(expected = "cannot select more landmarks")]
+  fn test_too_many_landmarks() {
+    let cloud = Cloud::new(circle(5, 1.0));
+    LandmarkSelection::MaxMin { count: 6, start: 0 }.select(&cloud);
+  }
+
+  #[test]
+  fn test_witness_edge_values() {
+    // Two landmarks at distance 2 and a witness halfway between them.
+    let cloud = Cloud::new(vec![
+      SVector::from([0.0, 0.0]),
+      SVector::from([2.0, 0.0]),
+      SVector::from([1.0, 0.0]),
+    ]);
+    let selection = LandmarkSelection::MaxMin { count: 2, start: 0 };
+    let value = |nu: usize| {
+      let filtered = LazyWitness::new(selection).with_nu(nu).build_filtration(&cloud, None);
+      let edge = filtered.complex().elements_of_dimension(1)[0].clone();
+      assert_eq!(edge.vertices(), &[0, 1]);
+      filtered.value(&edge).unwrap()
+    };
+
+    // With ν = 0 the midpoint witnesses the edge once R reaches its distance to both landmarks.
+    // For ν ≥ 1 its distance to the closest landmark is available as slack, which makes the
+    // edge appear right away.
+    assert!((value(0) - 1.0).abs() < 1e-12);
+    assert!(value(1).abs() < 1e-12);
+    assert!(value(2).abs() < 1e-12);
+  }
+
+  #[test]
+  fn test_witness_filtration_of_annulus() {
+    // Two rings of 400 witnesses each, summarised by 20 landmarks.
+    let mut points = circle(400, 1.0);
+    points.extend(circle(400, 1.2));
+    let cloud = Cloud::new(points);
+    let selection = LandmarkSelection::MaxMin { count: 20, start: 0 };
+    let witness = LazyWitness::new(selection).with_max_dimension(2);
+    let filtered = witness.build_filtration(&cloud, None);
+
+    assert!(filtered.is_monotone());
+    assert_eq!(filtered.complex().elements_of_dimension(0).len(), 20);
+    let labels: HashSet<usize> = selection.select(&cloud).into_iter().collect();
+    assert!(
+      filtered.complex().elements_of_dimension(0).iter().all(|v| labels.contains(&v.vertices()[0]))
+    );
+    assert!(filtered.complex().elements.values().all(|simplex| simplex.dimension() <= 2));
+
+    let complex = filtered.truncate(0.3);
+    assert_eq!(complex.homology::<Boolean>(0).betti_number, 1);
+    assert_eq!(complex.homology::<Boolean>(1).betti_number, 1);
+
+    let bounded = witness.build_filtration(&cloud, Some(0.3));
+    assert_eq!(bounded.complex().f_vector(), complex.f_vector());
+  }
+}
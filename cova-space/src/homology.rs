@@ -32,10 +32,18 @@
 //!   $\beta_k = \text{dim}(H_k(X; R))$, is called the $k$-th Betti number. It counts the number of
 //!   $k$-dimensional holes in $X$.
 //!
+//! - **Cohomology Groups**: Dually, a $k$-cochain is a function on the $k$-items, and the
+//!   coboundary $\delta^k: C^k(X; R) \to C^{k+1}(X; R)$ is the transpose of $\partial_{k+1}$. The
+//!   $k$-th cohomology group is the quotient $H^k(X; R) = \ker(\delta^k) / \text{im}(\delta^{k-1})$
+//!   of cocycles by coboundaries. Over a field it has the same dimension as $H_k(X; R)$, but its
+//!   representatives are functions on cells, which is what circular coordinates and cup products
+//!   are built from.
+//!
 //! This module provides the [`Chain`] struct to represent chains and implements
 //! operations like addition, negation, and multiplication by a scalar (coefficient).
 //! The [`Homology`] struct is used to store the results of a homology computation,
-//! including Betti numbers and generators for the homology groups.
+//! including Betti numbers and generators for the homology groups, and [`Cohomology`] does the
+//! same for cohomology with cocycle representatives.
 //!
 //! The actual computation of homology groups (i.e., finding $Z_k$ and $B_k$ and
 //! then the quotient) typically involves constructing chain complexes and computing
@@ -150,6 +158,35 @@ impl<'a, T: Topology, R: Ring> Chain<'a, T, R> {
     total_boundary
   }
 
+  /// Creates a chain from a coefficient vector in a given basis.
+  ///
+  /// This is the inverse of [`Chain::to_coeff_vector`]: entry `i` of `coeffs` becomes the
+  /// coefficient of `basis[i]`. Entries that are zero are omitted, so the resulting chain only
+  /// contains the items in its support, in basis order.
+  ///
+  /// # Arguments
+  ///
+  /// * `space`: A reference to the topological space.
+  /// * `basis`: The items indexing the entries of `coeffs`.
+  /// * `coeffs`: The coefficient vector.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `basis` and `coeffs` have different lengths.
+  pub fn from_coeff_vector(space: &'a T, basis: &[T::Item], coeffs: &DVector<R>) -> Self
+  where
+    T::Item: Clone,
+    R: Copy, {
+    assert_eq!(basis.len(), coeffs.len(), "basis and coefficient vector must have the same length");
+    let (items, coefficients) = basis
+      .iter()
+      .zip(coeffs.iter())
+      .filter(|(_, coeff)| !coeff.is_zero())
+      .map(|(item, coeff)| (item.clone(), *coeff))
+      .unzip();
+    Self { space, items, coefficients }
+  }
+
   /// Converts this chain to a coefficient vector in a given basis.
   ///
   /// The basis is implicitly defined by `basis_map`, which maps items (basis elements)
@@ -422,7 +459,10 @@ where R: Ring + Copy {
   /// A basis for the homology group $H_k = Z_k / B_k$.
   ///
   /// Each element is a [`DVector<R>`] representing a homology class generator.
-  /// These vectors are typically coefficient vectors in some chosen basis for the $k$-cycles.
+  /// These vectors are coefficient vectors of $k$-cycles in the basis of $k$-elements used to
+  /// compute them; for a [`crate::complexes::Complex`] this is
+  /// [`crate::complexes::Complex::basis`], and
+  /// [`crate::complexes::Complex::homology_generator_chains`] turns them back into [`Chain`]s.
   pub homology_generators: Vec<DVector<R>>,
 }
 
@@ -446,3 +486,69 @@ where R: Ring + Copy
     Self { dimension, betti_number: 0, homology_generators: Vec::new() }
   }
 }
+
+/// Represents the $k$-th cohomology group $H^k(X; R)$ of a topological space $X$
+/// with coefficients in a ring $R$.
+///
+/// It stores the Betti number (rank of the cohomology group) and a set of cocycles
+/// representing a basis of the cohomology group.
+///
+/// # Type Parameters
+///
+/// * `R`: The type of the coefficients, which must implement [`Ring`] and `Copy`.
+#[derive(Debug, Clone)]
+pub struct Cohomology<R>
+where R: Ring + Copy {
+  /// The dimension $k$ for which this cohomology group $H^k$ is computed.
+  pub dimension:               usize,
+  /// The Betti number $\beta^k = \text{rank}(H^k(X; R))$.
+  ///
+  /// For field coefficients this equals the homological Betti number $\beta_k$.
+  pub betti_number:            usize,
+  /// Cocycles whose classes form a basis of $H^k = Z^k / B^k$.
+  ///
+  /// Each element is a [`DVector<R>`] assigning a value to every $k$-item, in the same basis order
+  /// as the columns of the corresponding coboundary matrix.
+  pub cocycle_representatives: Vec<DVector<R>>,
+}
+
+impl<R> Cohomology<R>
+where R: Ring + Copy
+{
+  /// Creates a trivial cohomology group for a given dimension.
+  ///
+  /// A trivial cohomology group has a Betti number of 0 and no representatives.
+  ///
+  /// # Arguments
+  ///
+  /// * `dimension`: The dimension $k$ for this trivial cohomology group $H^k$.
+  pub const fn trivial(dimension: usize) -> Self {
+    Self { dimension, betti_number: 0, cocycle_representatives: Vec::new() }
+  }
+}
+
+/// Represents the $k$-th homology group $H_k(X; \mathbb{Z})$ with integer coefficients.
+///
+/// By the structure theorem for finitely generated abelian groups,
+/// $$ H_k(X; \mathbb{Z}) \cong \mathbb{Z}^{\beta_k} \oplus \mathbb{Z}/d_1 \oplus \cdots \oplus
+/// \mathbb{Z}/d_m $$
+/// with $d_1 \mid d_2 \mid \cdots \mid d_m$ and every $d_i > 1$.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntegralHomology {
+  /// The dimension $k$ for which this homology group $H_k$ is computed.
+  pub dimension:            usize,
+  /// The free rank $\beta_k$, which equals the Betti number over $\mathbb{Q}$.
+  pub free_rank:            usize,
+  /// The torsion coefficients $d_1 \mid d_2 \mid \cdots \mid d_m$, each greater than one.
+  pub torsion_coefficients: Vec<i64>,
+}
+
+impl IntegralHomology {
+  /// Creates a trivial integral homology group for a given dimension.
+  pub const fn trivial(dimension: usize) -> Self {
+    Self { dimension, free_rank: 0, torsion_coefficients: Vec::new() }
+  }
+
+  /// Returns `true` if the group has torsion.
+  pub fn has_torsion(&self) -> bool { !self.torsion_coefficients.is_empty() }
+}
@@ -65,18 +65,16 @@ use crate::set::{Collection, Poset};
 
 /// A node in a lattice representing an element and its relationships.
 ///
-/// Each node stores an element of type `T` and maintains sets of its direct
-/// successors (elements greater than this one) and direct predecessors
-/// (elements less than this one) in the partial order.
+/// Each node maintains the sets of successors (elements greater than this one) and
+/// predecessors (elements less than this one) of its element in the partial order. Both sets
+/// are kept transitively closed.
 #[derive(Debug, Clone)]
 pub struct LatticeNode<T> {
-  /// The element stored in this node.
-  element:      T,
-  /// Direct successors (elements that are greater than this one according to the
-  /// lattice's partial order).
+  /// All successors (elements that are greater than this one according to the
+  /// lattice's partial order), not only the covering ones.
   successors:   HashSet<T>,
-  /// Direct predecessors (elements that are less than this one according to the
-  /// lattice's partial order).
+  /// All predecessors (elements that are less than this one according to the
+  /// lattice's partial order), not only the covered ones.
   predecessors: HashSet<T>,
 }
 
@@ -123,13 +121,10 @@ impl<T: Hash + Eq + Clone> Lattice<T> {
   /// lattice.add_element(1);
   /// ```
   pub fn add_element(&mut self, element: T) {
-    if !self.nodes.contains_key(&element) {
-      self.nodes.insert(element.clone(), LatticeNode {
-        element,
-        successors: HashSet::new(),
-        predecessors: HashSet::new(),
-      });
-    }
+    self.nodes.entry(element).or_insert_with(|| LatticeNode {
+      successors:   HashSet::new(),
+      predecessors: HashSet::new(),
+    });
   }
 
   /// Adds a relation `a ≤ b` to the lattice.
@@ -139,7 +134,8 @@ impl<T: Hash + Eq + Clone> Lattice<T> {
   /// they are added.
   ///
   /// After adding the direct relation, this method also updates the transitive
-  /// closure of the lattice to ensure all indirect relationships are captured.
+  /// closure of the lattice to ensure all indirect relationships are captured. The update is
+  /// incremental: it only touches elements below `a` and above `b`.
   ///
   /// # Arguments
   ///
@@ -157,48 +153,22 @@ impl<T: Hash + Eq + Clone> Lattice<T> {
     self.add_element(a.clone());
     self.add_element(b.clone());
 
-    // Add direct relation
-    if let Some(node_a) = self.nodes.get_mut(&a) {
-      node_a.successors.insert(b.clone());
-    }
-    if let Some(node_b) = self.nodes.get_mut(&b) {
-      node_b.predecessors.insert(a);
-    }
-
-    // Transitive closure
-    self.compute_transitive_closure();
-  }
-
-  /// Computes the transitive closure of the lattice
-  fn compute_transitive_closure(&mut self) {
-    let mut changed = true;
-    while changed {
-      changed = false;
-      let mut updates = Vec::new();
-
-      // Collect all updates first
-      for node in self.nodes.values() {
-        for succ in &node.successors {
-          if let Some(succ_node) = self.nodes.get(succ) {
-            for succ_succ in &succ_node.successors {
-              updates.push((node.element.clone(), succ_succ.clone()));
-            }
-          }
-        }
+    // The stored relation is already transitively closed, so the only new pairs are
+    // x ≤ y for x in ↓a and y in ↑b. Updating just those keeps insertion local instead of
+    // recomputing the closure of the whole lattice.
+    let mut lower: Vec<T> = self.nodes[&a].predecessors.iter().cloned().collect();
+    lower.push(a);
+    let mut upper: Vec<T> = self.nodes[&b].successors.iter().cloned().collect();
+    upper.push(b);
+
+    for x in &lower {
+      if let Some(node_x) = self.nodes.get_mut(x) {
+        node_x.successors.extend(upper.iter().cloned());
       }
-
-      // Apply updates
-      for (a, b) in updates {
-        if let Some(node_a) = self.nodes.get_mut(&a)
-          && node_a.successors.insert(b.clone())
-        {
-          changed = true;
-        }
-        if let Some(node_b) = self.nodes.get_mut(&b)
-          && node_b.predecessors.insert(a)
-        {
-          changed = true;
-        }
+    }
+    for y in &upper {
+      if let Some(node_y) = self.nodes.get_mut(y) {
+        node_y.predecessors.extend(lower.iter().cloned());
       }
     }
   }
@@ -450,12 +420,11 @@ impl<T: Hash + Eq + Clone> Poset for Lattice<T> {
   ///
   /// A `HashSet` containing all elements in the lattice that are less than or equal to `a`.
   fn downset(&self, a: T) -> HashSet<T> {
-    self
-      .nodes
-      .iter()
-      .filter(|(_, node)| self.leq(&node.element, &a).unwrap_or(false))
-      .map(|(element, _)| element.clone())
-      .collect()
+    self.nodes.get(&a).map_or_else(HashSet::new, |node| {
+      let mut downset = node.predecessors.clone();
+      downset.insert(a);
+      downset
+    })
   }
 
   /// Returns the set of elements that are greater than or equal to `a`.
@@ -472,12 +441,11 @@ impl<T: Hash + Eq + Clone> Poset for Lattice<T> {
   ///
   /// A `HashSet` containing all elements in the lattice that are greater than or equal to `a`.
   fn upset(&self, a: T) -> HashSet<T> {
-    self
-      .nodes
-      .iter()
-      .filter(|(_, node)| self.leq(&a, &node.element).unwrap_or(false))
-      .map(|(element, _)| element.clone())
-      .collect()
+    self.nodes.get(&a).map_or_else(HashSet::new, |node| {
+      let mut upset = node.successors.clone();
+      upset.insert(a);
+      upset
+    })
   }
 
   /// Returns the set of elements that are successors of `a`.
@@ -865,4 +833,17 @@ mod tests {
     let predecessors = lattice.predecessors(4);
     assert_eq!(predecessors, HashSet::from([2, 3]));
   }
+
+  #[test]
+  fn test_transitive_closure_out_of_order() {
+    // Link two chains 1 ≤ 2 and 3 ≤ 4 in the middle after both exist.
+    let mut lattice = Lattice::new();
+    lattice.add_relation(1, 2);
+    lattice.add_relation(3, 4);
+    lattice.add_relation(2, 3);
+    assert!(lattice.leq(&1, &4).unwrap());
+    assert_eq!(lattice.downset(4), HashSet::from([1, 2, 3, 4]));
+    assert_eq!(lattice.upset(1), HashSet::from([1, 2, 3, 4]));
+    assert_eq!(lattice.successors(2), HashSet::from([3]));
+  }
 }
@@ -4,10 +4,12 @@
 pub mod cloud;
 pub mod complexes;
 pub mod definitions;
+pub mod discrete_morse;
 pub mod filtration;
 pub mod graph;
 pub mod homology;
 pub mod lattice;
+pub mod persistence;
 pub mod set;
 pub mod sheaf;
 
@@ -0,0 +1,479 @@
+//! # Distances Between Persistence Diagrams
+//!
+//! This module implements the two standard metrics on persistence diagrams, both computed
+//! exactly as optimal matchings.
+//!
+//! ## Mathematical Background
+//!
+//! A **partial matching** between diagrams $X$ and $Y$ pairs some points of $X$ with points of
+//! $Y$; every unmatched point is instead paired with its closest point on the diagonal
+//! $\Delta = \{(t, t)\}$. Points are compared in the $L^\infty$ norm, so a point $(b, d)$ is at
+//! distance $(d - b)/2$ from the diagonal.
+//!
+//! The **bottleneck distance** is the smallest achievable cost of the most expensive pair,
+//! $$ d_B(X, Y) = \inf_{\gamma} \sup_{x} \lVert x - \gamma(x) \rVert_\infty, $$
+//! and the **$p$-Wasserstein distance** is the smallest achievable $p$-norm of all costs,
+//! $$ W_p(X, Y) = \Big( \inf_{\gamma} \sum_{x} \lVert x - \gamma(x) \rVert_\infty^p \Big)^{1/p}. $$
+//!
+//! Both are stable: perturbing a filtration by at most $\varepsilon$ in the sup norm moves its
+//! diagrams by at most $\varepsilon$ in bottleneck distance.
+//!
+//! ## Essential Classes
+//!
+//! Essential intervals $[b, \infty)$ can only be matched with each other. If the two diagrams have
+//! different numbers of essential intervals the distance is infinite; otherwise essential
+//! intervals are matched in order of birth, which is optimal for both metrics.
+//!
+//! ## Algorithms
+//!
+//! - Wasserstein distances solve the assignment problem on the diagram points augmented with
+//!   diagonal projections, using the Hungarian algorithm in $O((n + m)^3)$.
+//! - Bottleneck distances binary search over the finitely many candidate costs, testing each one
+//!   for a perfect matching with the Hopcroft–Karp algorithm.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use cova_space::persistence::{
+//!   PersistenceDiagram, PersistenceInterval, bottleneck_distance, wasserstein_distance,
+//! };
+//!
+//! let mut a = PersistenceDiagram::new(1);
+//! a.intervals.push(PersistenceInterval::new(0.0, 2.0));
+//! let mut b = PersistenceDiagram::new(1);
+//! b.intervals.push(PersistenceInterval::new(0.0, 3.0));
+//!
+//! // Matching the two points directly moves the death by 1.
+//! assert_eq!(bottleneck_distance(&a, &b), 1.0);
+//! assert_eq!(wasserstein_distance(&a, &b, 2.0), 1.0);
+//! ```
+
+use std::collections::VecDeque;
+
+use super::{PersistenceDiagram, PersistenceInterval};
+
+/// Computes the bottleneck distance between two persistence diagrams.
+///
+/// Returns `f64::INFINITY` if the diagrams have different numbers of essential intervals.
+///
+/// # Examples
+///
+/// ```rust
+/// use cova_space::persistence::{PersistenceDiagram, PersistenceInterval, bottleneck_distance};
+///
+/// let mut a = PersistenceDiagram::new(0);
+/// a.intervals.push(PersistenceInterval::new(0.0, 4.0));
+/// let b = PersistenceDiagram::new(0);
+///
+/// // The only point is matched to the diagonal at distance (4 - 0) / 2.
+/// assert_eq!(bottleneck_distance(&a, &b), 2.0);
+/// ```
+pub fn bottleneck_distance(a: &PersistenceDiagram, b: &PersistenceDiagram) -> f64 {
+  let Some(essential) = essential_costs(a, b) else { return f64::INFINITY };
+  let essential_cost = essential.into_iter().fold(0.0, f64::max);
+
+  let xs: Vec<PersistenceInterval> = a.finite().copied().collect();
+  let ys: Vec<PersistenceInterval> = b.finite().copied().collect();
+
+  let mut candidates: Vec<f64> = xs
+    .iter()
+    .flat_map(|x| ys.iter().map(move |y| point_distance(x, y)))
+    .chain(xs.iter().chain(ys.iter()).map(diagonal_distance))
+    .collect();
+  candidates.push(0.0);
+  candidates.sort_by(f64::total_cmp);
+  candidates.dedup();
+
+  // The largest candidate always admits a perfect matching (everything to the diagonal), so the
+  // search for the smallest feasible candidate is well defined.
+  let (mut low, mut high) = (0, candidates.len() - 1);
+  while low < high {
+    let mid = usize::midpoint(low, high);
+    if has_perfect_matching(&xs, &ys, candidates[mid]) {
+      high = mid;
+    } else {
+      low = mid + 1;
+    }
+  }
+
+  candidates[low].max(essential_cost)
+}
+
+/// Computes the $p$-Wasserstein distance between two persistence diagrams.
+///
+/// Returns `f64::INFINITY` if the diagrams have different numbers of essential intervals.
+///
+/// # Panics
+///
+/// Panics if `p < 1`, since the formula does not define a metric in that range.
+///
+/// # Examples
+///
+/// ```rust
+/// use cova_space::persistence::{PersistenceDiagram, PersistenceInterval, wasserstein_distance};
+///
+/// let mut a = PersistenceDiagram::new(1);
+/// a.intervals.push(PersistenceInterval::new(0.0, 2.0));
+/// a.intervals.push(PersistenceInterval::new(0.0, 4.0));
+/// let b = PersistenceDiagram::new(1);
+///
+/// // Both points go to the diagonal, at distances 1 and 2.
+/// assert_eq!(wasserstein_distance(&a, &b, 1.0), 3.0);
+/// ```
+pub fn wasserstein_distance(a: &PersistenceDiagram, b: &PersistenceDiagram, p: f64) -> f64 {
+  assert!(p >= 1.0, "the Wasserstein exponent must satisfy p >= 1, got {p}");
+  if p == f64::INFINITY {
+    return bottleneck_distance(a, b);
+  }
+
+  let Some(essential) = essential_costs(a, b) else { return f64::INFINITY };
+  let essential_cost: f64 = essential.into_iter().map(|cost| cost.powf(p)).sum();
+
+  let xs: Vec<PersistenceInterval> = a.finite().copied().collect();
+  let ys: Vec<PersistenceInterval> = b.finite().copied().collect();
+  let (n, m) = (xs.len(), ys.len());
+
+  // Rows are the points of `a` followed by `m` diagonal slots, columns are the points of `b`
+  // followed by `n` diagonal slots. Diagonal slots match each other for free.
+  let size = n + m;
+  let mut cost = vec![vec![0.0; size]; size];
+  for (i, row) in cost.iter_mut().enumerate() {
+    for (j, entry) in row.iter_mut().enumerate() {
+      *entry = match (xs.get(i), ys.get(j)) {
+        (Some(x), Some(y)) => point_distance(x, y).powf(p),
+        (Some(x), None) => diagonal_distance(x).powf(p),
+        (None, Some(y)) => diagonal_distance(y).powf(p),
+        (None, None) => 0.0,
+      };
+    }
+  }
+
+  (hungarian(&cost) + essential_cost).powf(1.0 / p)
+}
+
+/// The $L^\infty$ distance between two finite diagram points.
+fn point_distance(x: &PersistenceInterval, y: &PersistenceInterval) -> f64 {
+  (x.birth - y.birth).abs().max((x.death - y.death).abs())
+}
+
+/// The $L^\infty$ distance from a finite diagram point to the diagonal.
+fn diagonal_distance(x: &PersistenceInterval) -> f64 { (x.death - x.birth) / 2.0 }
+
+/// Matches essential intervals by birth, returning the cost of each pair, or `None` if the
+/// diagrams have different numbers of essential intervals.
+fn essential_costs(a: &PersistenceDiagram, b: &PersistenceDiagram) -> Option<Vec<f64>> {
+  let mut births_a: Vec<f64> = a.essential().map(|interval| interval.birth).collect();
+  let mut births_b: Vec<f64> = b.essential().map(|interval| interval.birth).collect();
+  if births_a.len() != births_b.len() {
+    return None;
+  }
+  births_a.sort_by(f64::total_cmp);
+  births_b.sort_by(f64::total_cmp);
+  Some(births_a.iter().zip(&births_b).map(|(x, y)| (x - y).abs()).collect())
+}
+
+/// Checks whether the diagrams can be matched with every pair costing at most `threshold`.
+///
+/// The bipartite graph has the points of `xs` plus one diagonal slot per point of `ys` on the
+/// left, and the points of `ys` plus one diagonal slot per point of `xs` on the right. A point
+/// only needs its own diagonal slot, while diagonal slots can always be matched to each other.
+fn has_perfect_matching(
+  xs: &[PersistenceInterval],
+  ys: &[PersistenceInterval],
+  threshold: f64,
+) -> bool {
+  let (n, m) = (xs.len(), ys.len());
+  let size = n + m;
+  let mut adjacency = vec![Vec::new(); size];
+
+  for (i, x) in xs.iter().enumerate() {
+    for (j, y) in ys.iter().enumerate() {
+      if point_distance(x, y) <= threshold {
+        adjacency[i].push(j);
+      }
+    }
+    if diagonal_distance(x) <= threshold {
+      adjacency[i].push(m + i);
+    }
+  }
+  for (j, y) in ys.iter().enumerate() {
+    let row = n + j;
+    if diagonal_distance(y) <= threshold {
+      adjacency[row].push(j);
+    }
+    adjacency[row].extend(m..size);
+  }
+
+  hopcroft_karp(&adjacency, size) == size
+}
+
+/// Returns the size of a maximum matching in a bipartite graph with `left.len()` left vertices
+/// and `num_right` right vertices.
+fn hopcroft_karp(left: &[Vec<usize>], num_right: usize) -> usize {
+  const UNMATCHED: usize = usize::MAX;
+
+  let mut match_left = vec![UNMATCHED; left.len()];
+  let mut match_right = vec![UNMATCHED; num_right];
+  let mut layer = vec![0usize; left.len()];
+  let mut matching = 0;
+
+  loop {
+    // Breadth-first search from free left vertices builds the layered graph.
+    let mut queue = VecDeque::new();
+    for (u, matched) in match_left.iter().enumerate() {
+      if *matched == UNMATCHED {
+        layer[u] = 0;
+        queue.push_back(u);
+      } else {
+        layer[u] = usize::MAX;
+      }
+    }
+    let mut found_augmenting_path = false;
+    while let Some(u) = queue.pop_front() {
+      for &v in &left[u] {
+        match match_right[v] {
+          UNMATCHED => found_augmenting_path = true,
+          w if layer[w] == usize::MAX => {
+            layer[w] = layer[u] + 1;
+            queue.push_back(w);
+          },
+          _ => {},
+        }
+      }
+    }
+    if !found_augmenting_path {
+      return matching;
+    }
+
+    // Depth-first search augments along vertex-disjoint shortest paths.
+    for u in 0..left.len() {
+      if match_left[u] == UNMATCHED
+        && augment(u, left, &mut match_left, &mut match_right, &mut layer)
+      {
+        matching += 1;
+      }
+    }
+  }
+
+  fn augment(
+    u: usize,
+    left: &[Vec<usize>],
+    match_left: &mut [usize],
+    match_right: &mut [usize],
+    layer: &mut [usize],
+  ) -> bool {
+    for &v in &left[u] {
+      let w = match_right[v];
+      if w == UNMATCHED
+        || (layer[w] == layer[u] + 1 && augment(w, left, match_left, match_right, layer))
+      {
+        match_left[u] = v;
+        match_right[v] = u;
+        return true;
+      }
+    }
+    layer[u] = usize::MAX;
+    false
+  }
+}
+
+/// Solves the square assignment problem, returning the minimum total cost.
+///
+/// This is the classical $O(n^3)$ Hungarian algorithm with row and column potentials.
+fn hungarian(cost: &[Vec<f64>]) -> f64 {
+  let n = cost.len();
+  if n == 0 {
+    return 0.0;
+  }
+
+  // 1-indexed arrays; column 0 is a virtual column used to start each augmentation.
+  let mut row_potential = vec![0.0; n + 1];
+  let mut column_potential = vec![0.0; n + 1];
+  let mut assigned_row = vec![0usize; n + 1];
+  let mut previous_column = vec![0usize; n + 1];
+
+  for row in 1..=n {
+    assigned_row[0] = row;
+    let mut column = 0;
+    let mut min_slack = vec![f64::INFINITY; n + 1];
+    let mut used = vec![false; n + 1];
+
+    loop {
+      used[column] = true;
+      let current_row = assigned_row[column];
+      let mut delta = f64::INFINITY;
+      let mut next_column = 0;
+      for j in 1..=n {
+        if used[j] {
+          continue;
+        }
+        let slack = cost[current_row - 1][j - 1] - row_potential[current_row] - column_potential[j];
+        if slack < min_slack[j] {
+          min_slack[j] = slack;
+          previous_column[j] = column;
+        }
+        if min_slack[j] < delta {
+          delta = min_slack[j];
+          next_column = j;
+        }
+      }
+      for j in 0..=n {
+        if used[j] {
+          row_potential[assigned_row[j]] += delta;
+          column_potential[j] -= delta;
+        } else {
+          min_slack[j] -= delta;
+        }
+      }
+      column = next_column;
+      if assigned_row[column] == 0 {
+        break;
+      }
+    }
+
+    // Walk back along the alternating path, flipping assignments.
+    while column != 0 {
+      let previous = previous_column[column];
+      assigned_row[column] = assigned_row[previous];
+      column = previous;
+    }
+  }
+
+  (1..=n).map(|j| cost[assigned_row[j] - 1][j - 1]).sum()
+}
+
+#[cfg(test)]
+mod tests {
+  #![allow(clippy::float_cmp)]
+
+  use super::*;
+
+  fn diagram(points: &[(f64, f64)]) -> PersistenceDiagram {
+    PersistenceDiagram {
+      dimension: 1,
+      intervals: points
+        .iter()
+        .map(|&(birth, death)| PersistenceInterval::new(birth, death))
+        .collect(),
+    }
+  }
+
+  /// Brute-force optimal matching costs over all partial matchings, for cross-checking.
+  fn brute_force(xs: &[(f64, f64)], ys: &[(f64, f64)], p: f64) -> (f64, f64) {
+    fn search(
+      i: usize,
+      xs: &[PersistenceInterval],
+      ys: &[PersistenceInterval],
+      used: &mut Vec<bool>,
+      costs: &mut Vec<f64>,
+      best: &mut (f64, f64),
+      p: f64,
+    ) {
+      if i == xs.len() {
+        let mut all = costs.clone();
+        all.extend(
+          ys.iter().zip(used.iter()).filter(|(_, u)| !**u).map(|(y, _)| diagonal_distance(y)),
+        );
+        let bottleneck = all.iter().copied().fold(0.0, f64::max);
+        let wasserstein = all.iter().map(|c| c.powf(p)).sum::<f64>().powf(1.0 / p);
+        best.0 = best.0.min(bottleneck);
+        best.1 = best.1.min(wasserstein);
+        return;
+      }
+      costs.push(diagonal_distance(&xs[i]));
+      search(i + 1, xs, ys, used, costs, best, p);
+      costs.pop();
+      for j in 0..ys.len() {
+        if !used[j] {
+          used[j] = true;
+          costs.push(point_distance(&xs[i], &ys[j]));
+          search(i + 1, xs, ys, used, costs, best, p);
+          costs.pop();
+          used[j] = false;
+        }
+      }
+    }
+
+    let xs: Vec<_> = xs.iter().map(|&(b, d)| PersistenceInterval::new(b, d)).collect();
+    let ys: Vec<_> = ys.iter().map(|&(b, d)| PersistenceInterval::new(b, d)).collect();
+    let mut best = (f64::INFINITY, f64::INFINITY);
+    search(0, &xs, &ys, &mut vec![false; ys.len()], &mut Vec::new(), &mut best, p);
+    best
+  }
+
+  #[test]
+  fn test_identical_diagrams() {
+    let a = diagram(&[(0.0, 1.0), (0.5, 3.0), (2.0, f64::INFINITY)]);
+    assert_eq!(bottleneck_distance(&a, &a), 0.0);
+    assert_eq!(wasserstein_distance(&a, &a, 1.0), 0.0);
+    assert_eq!(wasserstein_distance(&a, &a, 2.0), 0.0);
+  }
+
+  #[test]
+  fn test_empty_diagrams() {
+    let empty = diagram(&[]);
+    assert_eq!(bottleneck_distance(&empty, &empty), 0.0);
+    assert_eq!(wasserstein_distance(&empty, &empty, 1.0), 0.0);
+
+    let a = diagram(&[(1.0, 3.0), (0.0, 6.0)]);
+    assert_eq!(bottleneck_distance(&a, &empty), 3.0);
+    assert_eq!(wasserstein_distance(&a, &empty, 1.0), 4.0);
+    assert_eq!(wasserstein_distance(&a, &empty, 2.0), 10.0_f64.sqrt());
+  }
+
+  #[test]
+  fn test_diagonal_is_preferred_when_cheaper() {
+    // Matching the two points costs 5, sending both to the diagonal costs max(5, 0.5).
+    let a = diagram(&[(0.0, 10.0)]);
+    let b = diagram(&[(5.0, 6.0)]);
+    assert_eq!(bottleneck_distance(&a, &b), 5.0);
+    assert_eq!(wasserstein_distance(&a, &b, 1.0), 5.0);
+
+    // Here the diagonal is strictly better for the Wasserstein distance.
+    let a = diagram(&[(0.0, 1.0)]);
+    let b = diagram(&[(5.0, 6.0)]);
+    assert_eq!(bottleneck_distance(&a, &b), 0.5);
+    assert_eq!(wasserstein_distance(&a, &b, 1.0), 1.0);
+  }
+
+  #[test]
+  fn test_essential_intervals() {
+    let a = diagram(&[(0.0, f64::INFINITY), (3.0, f64::INFINITY)]);
+    let b = diagram(&[(1.0, f64::INFINITY), (3.5, f64::INFINITY)]);
+    assert_eq!(bottleneck_distance(&a, &b), 1.0);
+    assert_eq!(wasserstein_distance(&a, &b, 1.0), 1.5);
+
+    let c = diagram(&[(0.0, f64::INFINITY)]);
+    assert_eq!(bottleneck_distance(&a, &c), f64::INFINITY);
+    assert_eq!(wasserstein_distance(&a, &c, 1.0), f64::INFINITY);
+  }
+
+  #[test]
+  fn test_matches_brute_force() {
+    let xs = [(0.0, 2.0), (1.0, 4.5), (3.0, 3.5), (0.2, 7.0)];
+    let ys = [(0.1, 2.5), (1.5, 4.0), (5.0, 9.0)];
+    for p in [1.0, 2.0, 3.0] {
+      let (bottleneck, wasserstein) = brute_force(&xs, &ys, p);
+      let (a, b) = (diagram(&xs), diagram(&ys));
+      assert!((bottleneck_distance(&a, &b) - bottleneck).abs() < 1e-12);
+      assert!((wasserstein_distance(&a, &b, p) - wasserstein).abs() < 1e-9);
+      // Both metrics are symmetric.
+      assert!((bottleneck_distance(&b, &a) - bottleneck).abs() < 1e-12);
+      assert!((wasserstein_distance(&b, &a, p) - wasserstein).abs() < 1e-9);
+    }
+  }
+
+  #[test]
+  fn test_infinite_exponent_is_bottleneck() {
+    let a = diagram(&[(0.0, 2.0), (1.0, 5.0)]);
+    let b = diagram(&[(0.0, 3.0)]);
+    assert_eq!(wasserstein_distance(&a, &b, f64::INFINITY), bottleneck_distance(&a, &b));
+  }
+
+  #[test]
+  #[should_panic(expected = "p >= 1")]
+  fn test_invalid_exponent() {
+    let a = diagram(&[(0.0, 1.0)]);
+    wasserstein_distance(&a, &a, 0.5);
+  }
+}
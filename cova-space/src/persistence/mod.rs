@@ -0,0 +1,514 @@
+//! # Persistence Module
+//!
+//! This module computes **persistent homology** of filtered cell complexes. Rather than
+//! computing homology at a single scale, persistence tracks how homology classes are born and
+//! die as a complex is built up cell by cell, summarizing the result as a collection of
+//! birth/death intervals for each dimension.
+//!
+//! ## Mathematical Background
+//!
+//! A **filtration** of a complex $K$ is a nested sequence of subcomplexes
+//! $$ \emptyset = K_0 \subseteq K_1 \subseteq \cdots \subseteq K_m = K. $$
+//! Here it is specified by assigning a real **filtration value** $f(\sigma)$ to every cell, with
+//! the monotonicity requirement $f(\tau) \leq f(\sigma)$ whenever $\tau$ is a face of $\sigma$.
+//! The sublevel sets $K_t = \{ \sigma : f(\sigma) \leq t \}$ are then subcomplexes.
+//!
+//! The inclusions $K_s \hookrightarrow K_t$ induce maps $H_k(K_s; F) \to H_k(K_t; F)$ and, over a
+//! field $F$, the resulting persistence module decomposes uniquely into intervals $[b, d)$:
+//! - A class is **born** at $b$ when a cell creates a new cycle.
+//! - It **dies** at $d$ when a cell's boundary makes it homologous to an older class.
+//! - Classes that never die are **essential** and have $d = \infty$.
+//!
+//! The multiset of intervals in dimension $k$ is the **barcode**, or equivalently the
+//! **persistence diagram** $\text{Dgm}_k(f)$, represented here by [`PersistenceDiagram`].
+//!
+//! ## Algorithm
+//!
+//! Cells are sorted into a total order compatible with the filtration, and the boundary matrix
+//! $D$ is assembled in that order. The standard column reduction computes $R = DV$ such that the
+//! lowest non-zero entries ("pivots") of the non-zero columns of $R$ lie in distinct rows. If
+//! column $j$ of $R$ has its pivot in row $i$, then cell $i$ creates a class which cell $j$
+//! destroys; cells whose reduced column is zero and which are never a pivot are essential.
+//!
+//! Two standard optimizations are applied:
+//! - **Clearing (twist)**: Dimensions are reduced from the top down. Whenever column $j$ is found
+//!   to have pivot $i$, column $i$ must reduce to zero and is skipped entirely.
+//! - **Sparse columns**: Columns are stored as sorted lists of non-zero entries, so each
+//!   elimination only touches the entries that are actually present.
+//!
+//! The reduction works over any [`Field`], so torsion can be detected by comparing diagrams over
+//! different prime fields.
+//!
+//! ## Submodules
+//!
+//! - [`distance`]: Bottleneck and Wasserstein distances between persistence diagrams
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use cova_algebra::algebras::boolean::Boolean;
+//! use cova_space::{
+//!   complexes::{Simplex, SimplicialComplex},
+//!   prelude::*,
+//! };
+//!
+//! // A triangle whose edges appear at time 1 and whose interior appears at time 2.
+//! let mut complex = SimplicialComplex::new();
+//! complex.join_element(Simplex::new(2, vec![0, 1, 2]));
+//!
+//! let diagrams = complex.persistent_homology::<Boolean>(|simplex| simplex.dimension() as f64);
+//!
+//! // One connected component lives forever, the loop lives on [1, 2).
+//! assert_eq!(diagrams[0].essential().count(), 1);
+//! assert_eq!(diagrams[1].intervals.len(), 1);
+//! assert_eq!(diagrams[1].intervals[0].birth, 1.0);
+//! assert_eq!(diagrams[1].intervals[0].death, 2.0);
+//! ```
+
+use std::{cmp::Ordering, collections::HashMap};
+
+use cova_algebra::rings::Field;
+
+pub mod distance;
+
+pub use distance::{bottleneck_distance, wasserstein_distance};
+
+use crate::{
+  complexes::{Complex, ComplexElement, FilteredComplex, filtered::compare_filtration},
+  definitions::Topology,
+};
+
+/// A single birth/death interval $[b, d)$ in a persistence diagram.
+///
+/// Essential classes, which never die, have `death == f64::INFINITY`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PersistenceInterval {
+  /// The filtration value at which the homology class is born.
+  pub birth: f64,
+  /// The filtration value at which the homology class dies, or `f64::INFINITY`.
+  pub death: f64,
+}
+
+impl PersistenceInterval {
+  /// Creates a new interval from its birth and death values.
+  pub const fn new(birth: f64, death: f64) -> Self { Self { birth, death } }
+
+  /// Returns `true` if the class represented by this interval never dies.
+  pub const fn is_essential(&self) -> bool { self.death == f64::INFINITY }
+
+  /// Returns the lifetime `death - birth` of the interval (infinite for essential classes).
+  pub fn persistence(&self) -> f64 { self.death - self.birth }
+
+  /// Returns `true` if the class is alive at filtration value `t`, i.e. `birth <= t < death`.
+  pub fn contains(&self, t: f64) -> bool { self.birth <= t && t < self.death }
+}
+
+/// The persistence diagram (equivalently, the barcode) of a filtration in a single dimension.
+///
+/// Intervals of zero length, which arise when a cell is created and destroyed at the same
+/// filtration value, carry no topological information and are not recorded.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PersistenceDiagram {
+  /// The homological dimension $k$ described by this diagram.
+  pub dimension: usize,
+  /// The birth/death intervals, sorted by birth and then by death.
+  pub intervals: Vec<PersistenceInterval>,
+}
+
+impl PersistenceDiagram {
+  /// Creates an empty diagram for the given dimension.
+  pub const fn new(dimension: usize) -> Self { Self { dimension, intervals: Vec::new() } }
+
+  /// Returns the number of intervals in the diagram.
+  pub fn len(&self) -> usize { self.intervals.len() }
+
+  /// Returns `true` if the diagram contains no intervals.
+  pub fn is_empty(&self) -> bool { self.intervals.is_empty() }
+
+  /// Returns an iterator over the essential (infinite) intervals.
+  pub fn essential(&self) -> impl Iterator<Item = &PersistenceInterval> {
+    self.intervals.iter().filter(|interval| interval.is_essential())
+  }
+
+  /// Returns an iterator over the finite intervals.
+  pub fn finite(&self) -> impl Iterator<Item = &PersistenceInterval> {
+    self.intervals.iter().filter(|interval| !interval.is_essential())
+  }
+
+  /// Returns the Betti number $\beta_k(K_t)$ of the sublevel complex at filtration value `t`.
+  ///
+  /// This is the number of intervals containing `t`, which by the structure theorem for
+  /// persistence modules equals the rank of $H_k(K_t)$.
+  pub fn betti_number_at(&self, t: f64) -> usize {
+    self.intervals.iter().filter(|interval| interval.contains(t)).count()
+  }
+
+  /// Sorts intervals by birth, breaking ties by death.
+  fn sort(&mut self) {
+    self.intervals.sort_by(|a, b| a.birth.total_cmp(&b.birth).then(a.death.total_cmp(&b.death)));
+  }
+}
+
+impl<T: ComplexElement> Complex<T> {
+  /// Computes the persistent homology of the filtration defined by `filtration`.
+  ///
+  /// The closure assigns a filtration value to every element of the complex. Values must be
+  /// monotone with respect to the face relation: every face must appear no later than the
+  /// elements it bounds.
+  ///
+  /// # Returns
+  ///
+  /// One [`PersistenceDiagram`] per dimension `0..=self.max_dimension()`, indexed by dimension.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the filtration is not monotone, i.e. some face has a strictly larger filtration
+  /// value than one of its cofaces.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use cova_algebra::algebras::boolean::Boolean;
+  /// use cova_space::complexes::{Simplex, SimplicialComplex};
+  ///
+  /// // A hollow triangle: the loop is born when the last edge appears and never dies.
+  /// let mut complex = SimplicialComplex::new();
+  /// complex.join_element(Simplex::new(1, vec![0, 1]));
+  /// complex.join_element(Simplex::new(1, vec![1, 2]));
+  /// complex.join_element(Simplex::new(1, vec![0, 2]));
+  ///
+  /// let diagrams = complex.persistent_homology::<Boolean>(|simplex| match simplex.vertices() {
+  ///   [0, 2] => 3.0,
+  ///   [_, _] => 1.0,
+  ///   _ => 0.0,
+  /// });
+  ///
+  /// assert_eq!(diagrams[1].intervals.len(), 1);
+  /// assert_eq!(diagrams[1].intervals[0].birth, 3.0);
+  /// assert!(diagrams[1].intervals[0].is_essential());
+  /// ```
+  pub fn persistent_homology<F: Field + Copy>(
+    &self,
+    filtration: impl Fn(&T) -> f64,
+  ) -> Vec<PersistenceDiagram> {
+    let mut cells: Vec<(T, f64)> =
+      self.elements.values().map(|element| (element.clone(), filtration(element))).collect();
+    cells.sort_by(|(a, value_a), (b, value_b)| compare_filtration(a, *value_a, b, *value_b));
+    persistence_of_order::<T, F>(self, &cells)
+  }
+}
+
+impl<T: ComplexElement> FilteredComplex<T> {
+  /// Computes the persistent homology of this filtration.
+  ///
+  /// Returns one [`PersistenceDiagram`] per dimension `0..=max_dimension`, indexed by dimension.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use cova_algebra::algebras::boolean::Boolean;
+  /// use cova_space::complexes::{FilteredComplex, Simplex};
+  ///
+  /// let mut filtered = FilteredComplex::new();
+  /// filtered.insert(Simplex::new(1, vec![0, 1]), 1.0);
+  /// filtered.insert(Simplex::new(1, vec![1, 2]), 1.0);
+  /// filtered.insert(Simplex::new(1, vec![0, 2]), 2.0);
+  /// filtered.insert(Simplex::new(2, vec![0, 1, 2]), 5.0);
+  ///
+  /// let diagrams = filtered.persistent_homology::<Boolean>();
+  /// assert_eq!(diagrams[1].intervals.len(), 1);
+  /// assert_eq!(diagrams[1].intervals[0].birth, 2.0);
+  /// assert_eq!(diagrams[1].intervals[0].death, 5.0);
+  /// ```
+  pub fn persistent_homology<F: Field + Copy>(&self) -> Vec<PersistenceDiagram> {
+    persistence_of_order::<T, F>(self.complex(), &self.filtration_order())
+  }
+}
+
+/// Assembles the filtered boundary matrix for `cells`, given in filtration order, and reduces it.
+fn persistence_of_order<T: ComplexElement, F: Field + Copy>(
+  complex: &Complex<T>,
+  cells: &[(T, f64)],
+) -> Vec<PersistenceDiagram> {
+  let index_of: HashMap<usize, usize> = cells
+    .iter()
+    .enumerate()
+    .filter_map(|(index, (element, _))| element.id().map(|id| (id, index)))
+    .collect();
+
+  let columns: Vec<Vec<(usize, F)>> = cells
+    .iter()
+    .enumerate()
+    .map(|(index, (element, _))| {
+      let boundary = complex.boundary::<F>(element);
+      let mut column: Vec<(usize, F)> = boundary
+        .items
+        .iter()
+        .zip(boundary.coefficients)
+        .map(|(face, coeff)| {
+          let row = index_of[&face.id().expect("complex elements always carry an ID")];
+          assert!(
+            row < index,
+            "filtration is not monotone: a face appears after one of its cofaces"
+          );
+          (row, coeff)
+        })
+        .collect();
+      column.sort_unstable_by_key(|(row, _)| *row);
+      column
+    })
+    .collect();
+
+  let dimensions: Vec<usize> = cells.iter().map(|(element, _)| element.dimension()).collect();
+  let values: Vec<f64> = cells.iter().map(|(_, value)| *value).collect();
+
+  reduce_filtration(columns, &dimensions, &values, complex.max_dimension())
+}
+
+/// Reduces a filtered boundary matrix and reads off the persistence diagrams.
+///
+/// `columns[j]` holds the non-zero entries of the boundary of cell `j`, sorted by row, where
+/// cells are indexed in filtration order. Reduction proceeds from the highest dimension down so
+/// that the columns of paired (positive) cells can be cleared without being reduced.
+fn reduce_filtration<F: Field + Copy>(
+  mut columns: Vec<Vec<(usize, F)>>,
+  dimensions: &[usize],
+  values: &[f64],
+  max_dimension: usize,
+) -> Vec<PersistenceDiagram> {
+  let num_cells = columns.len();
+  // `pivot_column[i] = Some(j)` when the reduced column `j` has its lowest entry in row `i`.
+  let mut pivot_column: Vec<Option<usize>> = vec![None; num_cells];
+  let mut cleared = vec![false; num_cells];
+
+  for dimension in (1..=max_dimension).rev() {
+    for j in 0..num_cells {
+      if dimensions[j] != dimension || cleared[j] {
+        continue;
+      }
+
+      let mut column = std::mem::take(&mut columns[j]);
+      while let Some(&(low, coeff)) = column.last() {
+        let Some(other) = pivot_column[low] else { break };
+        let other_coeff = columns[other].last().expect("pivot columns are non-empty").1;
+        let factor = -(coeff * other_coeff.multiplicative_inverse());
+        column = add_scaled_column(&column, &columns[other], factor);
+      }
+
+      if let Some(&(low, _)) = column.last() {
+        pivot_column[low] = Some(j);
+        cleared[low] = true;
+      }
+      columns[j] = column;
+    }
+  }
+
+  let mut diagrams: Vec<PersistenceDiagram> =
+    (0..=max_dimension).map(PersistenceDiagram::new).collect();
+
+  for (birth_index, death_index) in pivot_column.iter().enumerate() {
+    let dimension = dimensions[birth_index];
+    let birth = values[birth_index];
+    match death_index {
+      Some(death_index) => {
+        let death = values[*death_index];
+        if death > birth {
+          diagrams[dimension].intervals.push(PersistenceInterval::new(birth, death));
+        }
+      },
+      // A cell that kills no class and is killed by no cell creates an essential class.
+      None if columns[birth_index].is_empty() =>
+        diagrams[dimension].intervals.push(PersistenceInterval::new(birth, f64::INFINITY)),
+      None => {},
+    }
+  }
+
+  diagrams.iter_mut().for_each(PersistenceDiagram::sort);
+  diagrams
+}
+
+/// Returns `target + factor * source` for sparse columns sorted by row, dropping zeros.
+fn add_scaled_column<F: Field + Copy>(
+  target: &[(usize, F)],
+  source: &[(usize, F)],
+  factor: F,
+) -> Vec<(usize, F)> {
+  let mut result = Vec::with_capacity(target.len() + source.len());
+  let (mut i, mut j) = (0, 0);
+  while i < target.len() || j < source.len() {
+    let next = match (target.get(i), source.get(j)) {
+      (Some(&(row_t, coeff_t)), Some(&(row_s, coeff_s))) => match row_t.cmp(&row_s) {
+        Ordering::Less => {
+          i += 1;
+          (row_t, coeff_t)
+        },
+        Ordering::Greater => {
+          j += 1;
+          (row_s, factor * coeff_s)
+        },
+        Ordering::Equal => {
+          i += 1;
+          j += 1;
+          (row_t, coeff_t + factor * coeff_s)
+        },
+      },
+      (Some(&entry), None) => {
+        i += 1;
+        entry
+      },
+      (None, Some(&(row_s, coeff_s))) => {
+        j += 1;
+        (row_s, factor * coeff_s)
+      },
+      (None, None) => unreachable!(),
+    };
+    if !next.1.is_zero() {
+      result.push(next);
+    }
+  }
+  result
+}
+
+#[cfg(test)]
+mod tests {
+  #![allow(clippy::float_cmp)]
+
+  use cova_algebra::{algebras::boolean::Boolean, modular, prime_field};
+
+  use super::*;
+  use crate::complexes::{Cube, CubicalComplex, Simplex, SimplicialComplex};
+
+  modular!(Mod7, u32, 7);
+  prime_field!(Mod7);
+
+  /// A hollow square 0-1-2-3 whose edges appear at times 1, 2, 3, 4, filled at time 5.
+  fn filled_square_filtration(simplex: &Simplex) -> f64 {
+    match simplex.vertices() {
+      [_] => 0.0,
+      [0, 1] => 1.0,
+      [1, 2] => 2.0,
+      [2, 3] => 3.0,
+      [0, 3] => 4.0,
+      _ => 5.0,
+    }
+  }
+
+  fn filled_square() -> SimplicialComplex {
+    let mut complex = SimplicialComplex::new();
+    complex.join_element(Simplex::new(2, vec![0, 1, 2]));
+    complex.join_element(Simplex::new(2, vec![0, 2, 3]));
+    complex
+  }
+
+  fn check_filled_square<F: Field + Copy>() {
+    let complex = filled_square();
+    // The diagonal [0, 2] gets value 5.0 along with both triangles.
+    let diagrams = complex.persistent_homology::<F>(filled_square_filtration);
+    assert_eq!(diagrams.len(), 3);
+
+    // H0: four vertices born at 0, merged at times 1, 2, 3; one essential component.
+    let h0 = &diagrams[0];
+    assert_eq!(h0.len(), 4);
+    assert_eq!(h0.essential().count(), 1);
+    let mut deaths: Vec<f64> = h0.finite().map(|interval| interval.death).collect();
+    deaths.sort_by(f64::total_cmp);
+    assert_eq!(deaths, vec![1.0, 2.0, 3.0]);
+
+    // H1: the square loop is born at 4 and filled at 5.
+    let h1 = &diagrams[1];
+    assert_eq!(h1.intervals, vec![PersistenceInterval::new(4.0, 5.0)]);
+
+    // H2: nothing.
+    assert!(diagrams[2].is_empty());
+  }
+
+  #[test]
+  fn test_filled_square_persistence() {
+    check_filled_square::<Boolean>();
+    check_filled_square::<Mod7>();
+    check_filled_square::<f64>();
+  }
+
+  #[test]
+  fn test_essential_classes_match_homology() {
+    // Hollow tetrahedron (a 2-sphere) with arbitrary monotone filtration by dimension.
+    let mut complex = SimplicialComplex::new();
+    for face in [[0, 1, 2], [0, 1, 3], [0, 2, 3], [1, 2, 3]] {
+      complex.join_element(Simplex::new(2, face.to_vec()));
+    }
+
+    let diagrams = complex
+      .persistent_homology::<Mod7>(|simplex| simplex.vertices().iter().sum::<usize>() as f64);
+
+    for (k, diagram) in diagrams.iter().enumerate() {
+      assert_eq!(diagram.dimension, k);
+      assert_eq!(diagram.essential().count(), complex.homology::<Mod7>(k).betti_number);
+    }
+  }
+
+  #[test]
+  fn test_betti_number_at() {
+    let complex = filled_square();
+    let diagrams = complex.persistent_homology::<Boolean>(filled_square_filtration);
+
+    assert_eq!(diagrams[0].betti_number_at(0.0), 4);
+    assert_eq!(diagrams[0].betti_number_at(2.5), 2);
+    assert_eq!(diagrams[0].betti_number_at(10.0), 1);
+    assert_eq!(diagrams[1].betti_number_at(3.5), 0);
+    assert_eq!(diagrams[1].betti_number_at(4.5), 1);
+    assert_eq!(diagrams[1].betti_number_at(5.0), 0);
+  }
+
+  #[test]
+  fn test_zero_length_intervals_are_dropped() {
+    let complex = filled_square();
+    // Everything appears at once: only the essential component survives.
+    let diagrams = complex.persistent_homology::<Boolean>(|_| 0.0);
+
+    assert_eq!(diagrams[0].intervals, vec![PersistenceInterval::new(0.0, f64::INFINITY)]);
+    assert!(diagrams[1].is_empty());
+    assert!(diagrams[2].is_empty());
+  }
+
+  #[test]
+  fn test_cubical_persistence() {
+    let mut complex = CubicalComplex::new();
+    complex.join_element(Cube::square([0, 1, 2, 3]));
+
+    // Edges at 1, the square at 2: the boundary loop lives on [1, 2).
+    let diagrams = complex.persistent_homology::<Mod7>(|cube| cube.dimension as f64);
+
+    assert_eq!(diagrams[0].essential().count(), 1);
+    assert_eq!(diagrams[1].intervals, vec![PersistenceInterval::new(1.0, 2.0)]);
+  }
+
+  #[test]
+  fn test_filtered_complex_matches_closure() {
+    let complex = filled_square();
+    let filtered = FilteredComplex::from_complex(complex.clone(), filled_square_filtration);
+
+    assert_eq!(
+      filtered.persistent_homology::<Mod7>(),
+      complex.persistent_homology::<Mod7>(filled_square_filtration)
+    );
+  }
+
+  #[test]
+  fn test_empty_complex() {
+    let complex = SimplicialComplex::new();
+    let diagrams = complex.persistent_homology::<Boolean>(|_| 0.0);
+
+    assert_eq!(diagrams.len(), 1);
+    assert!(diagrams[0].is_empty());
+  }
+
+  #[test]
+  #[should_panic(expected = "filtration is not monotone")]
+  fn test_non_monotone_filtration_panics() {
+    let mut complex = SimplicialComplex::new();
+    complex.join_element(Simplex::new(1, vec![0, 1]));
+
+    // Vertices appear after the edge they bound.
+    complex.persistent_homology::<Boolean>(|simplex| 1.0 - simplex.dimension() as f64);
+  }
+}
@@ -48,7 +48,7 @@ use cova_algebra::tensors::{DMatrix, DVector};
 
 use super::*;
 use crate::{
-  complexes::{Complex, ComplexElement},
+  complexes::{Complex, ComplexElement, incidence_coefficient},
   definitions::Topology,
   set::Poset,
 };
@@ -233,8 +233,8 @@ where T: Hash + Eq + Clone + Debug
   /// The matrix has:
   /// - Rows indexed by (k+1)-dimensional elements
   /// - Columns indexed by k-dimensional elements
-  /// - Entry (σ, τ) equals the orientation coefficient of τ in ∂σ where σ is (k+1)-dimensional and
-  ///   τ is k-dimensional
+  /// - Block (σ, τ) is the restriction map from τ to σ scaled by the incidence number of τ in ∂σ,
+  ///   where σ is (k+1)-dimensional and τ is k-dimensional
   ///
   /// # Arguments
   /// * `dimension`: The dimension k of the domain (k-cochains)
@@ -327,22 +327,21 @@ where T: Hash + Eq + Clone + Debug
 
     for (row_idx, k_plus_1_element) in k_plus_1_elements.iter().enumerate() {
       for (col_idx, k_element) in k_elements.iter().enumerate() {
-        // Determine if k_element is in boundary
+        // The incidence number of k_element in the boundary of k_plus_1_element, summed over
+        // every time the face is listed.
         let boundary_with_orientations = k_plus_1_element.boundary_with_orientations();
-        if let Some((_, orientation_coeff)) =
-          boundary_with_orientations.iter().find(|(face, _)| face.same_content(k_element))
+        let mut incidences = boundary_with_orientations
+          .iter()
+          .filter(|(face, _)| face.same_content(k_element))
+          .map(|(_, incidence)| *incidence)
+          .peekable();
+        if incidences.peek().is_some()
           && let Some(restriction_matrix) =
             self.restrictions.get(&(k_element.clone(), k_plus_1_element.clone()))
         {
-          // Signed matrix
-          let mut signed = restriction_matrix.clone();
-          if *orientation_coeff < 0 {
-            for val in signed.iter_mut() {
-              *val = -*val;
-            }
-          } else if *orientation_coeff == 0 {
-            signed.fill(F::zero());
-          }
+          // Restriction scaled by the incidence number
+          let coefficient = incidence_coefficient::<F>(incidences.sum());
+          let signed = restriction_matrix.map(|entry| entry * coefficient);
 
           // Place into result
           let row_offset = row_offsets[row_idx];